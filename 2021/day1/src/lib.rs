@@ -0,0 +1,244 @@
+use {
+    anyhow::Result,
+    common::{input::lines_of, solution::Solution},
+    std::{cmp::Ordering, io::BufRead},
+};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 1;
+
+    type Input = Sonar;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        increases(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        Ok(input.increases)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        Ok(input.window_increases)
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Sonar {
+    increases: usize,
+    window_increases: usize,
+}
+
+pub fn increases<R: BufRead>(reader: R) -> Result<Sonar> {
+    let mut depths = Depths::new();
+    let mut sonar = Sonar::default();
+
+    for depth in lines_of::<u32>(reader) {
+        let (variance, window) = depths.push(depth?);
+        if variance == Variance::Increased {
+            sonar.increases += 1;
+        }
+        if let Some((_, Variance::Increased)) = window {
+            sonar.window_increases += 1;
+        }
+    }
+
+    Ok(sonar)
+}
+
+#[derive(Debug)]
+struct Depths {
+    previous: Option<u32>,
+    window: Window,
+    previous_window: Option<u32>,
+}
+
+impl Depths {
+    fn new() -> Self {
+        Depths {
+            previous: None,
+            window: Window::new(),
+            previous_window: None,
+        }
+    }
+
+    fn push(&mut self, depth: u32) -> (Variance, Option<(u32, Variance)>) {
+        let variance = Variance::compare(self.previous.as_ref(), Some(&depth));
+        self.previous = Some(depth);
+
+        self.window.push(Some(depth));
+        let window = self.window.is_full().then(|| {
+            let sum = self.window.sum();
+            let variance = Variance::compare(self.previous_window.as_ref(), Some(&sum));
+            self.previous_window = Some(sum);
+            (sum, variance)
+        });
+
+        (variance, window)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Variance {
+    Unchanged,
+    Increased,
+    Decreased,
+    NotApplicable,
+}
+
+impl Variance {
+    fn compare(first: Option<&u32>, second: Option<&u32>) -> Self {
+        match (first, second) {
+            (Some(first), Some(second)) => match first.cmp(second) {
+                Ordering::Less => Variance::Increased,
+                Ordering::Greater => Variance::Decreased,
+                _ => Variance::Unchanged,
+            },
+            (_, _) => Variance::NotApplicable,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Window {
+    a: Option<u32>,
+    b: Option<u32>,
+    c: Option<u32>,
+}
+
+impl Window {
+    fn new() -> Self {
+        Self {
+            a: None,
+            b: None,
+            c: None,
+        }
+    }
+
+    fn push(&mut self, item: Option<u32>) {
+        self.a = self.b;
+        self.b = self.c;
+        self.c = item;
+    }
+
+    fn sum(&self) -> u32 {
+        self.a.unwrap_or(0) + self.b.unwrap_or(0) + self.c.unwrap_or(0)
+    }
+
+    fn is_full(&self) -> bool {
+        self.a.is_some() && self.b.is_some() && self.c.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {self::Variance::*, super::*, std::io::Cursor};
+
+    const DEPTHS: [u32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    fn scan() -> Vec<(Variance, Option<(u32, Variance)>)> {
+        let mut depths = Depths::new();
+        DEPTHS.iter().map(|&depth| depths.push(depth)).collect()
+    }
+
+    #[test]
+    fn check_compare_lt() {
+        assert_eq!(Variance::compare(Some(&10), Some(&20)), Increased);
+    }
+
+    #[test]
+    fn check_compare_gt() {
+        assert_eq!(Variance::compare(Some(&20), Some(&10)), Decreased);
+    }
+
+    #[test]
+    fn check_compare_eq() {
+        assert_eq!(Variance::compare(Some(&10), Some(&10)), Unchanged);
+    }
+
+    #[test]
+    fn check_compare_first() {
+        assert_eq!(Variance::compare(None, Some(&10)), NotApplicable);
+    }
+
+    #[test]
+    fn check_compare_last() {
+        assert_eq!(Variance::compare(Some(&10), None), NotApplicable);
+    }
+
+    #[test]
+    fn check_variance() {
+        assert_eq!(
+            scan()
+                .into_iter()
+                .map(|(v, _)| v)
+                .collect::<Vec<Variance>>(),
+            vec![
+                NotApplicable,
+                Increased,
+                Increased,
+                Increased,
+                Decreased,
+                Increased,
+                Increased,
+                Increased,
+                Decreased,
+                Increased
+            ]
+        );
+    }
+
+    #[test]
+    fn check_count() -> Result<()> {
+        let input = DEPTHS.map(|d| d.to_string()).join("\n");
+
+        assert_eq!(increases(Cursor::new(&input))?.increases, 7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_windows() {
+        assert_eq!(
+            scan()
+                .into_iter()
+                .filter_map(|(_, w)| w.map(|(sum, _)| sum))
+                .collect::<Vec<u32>>(),
+            vec![607, 618, 618, 617, 647, 716, 769, 792]
+        )
+    }
+
+    #[test]
+    fn check_window_variances() {
+        assert_eq!(
+            scan()
+                .into_iter()
+                .filter_map(|(_, w)| w.map(|(_, v)| v))
+                .collect::<Vec<Variance>>(),
+            vec![
+                NotApplicable,
+                Increased,
+                Unchanged,
+                Decreased,
+                Increased,
+                Increased,
+                Increased,
+                Increased,
+            ]
+        )
+    }
+
+    #[test]
+    fn check_window_count() -> Result<()> {
+        let input = DEPTHS.map(|d| d.to_string()).join("\n");
+
+        assert_eq!(increases(Cursor::new(&input))?.window_increases, 5);
+        assert!(increases(Cursor::new("1\nx")).is_err());
+
+        Ok(())
+    }
+}
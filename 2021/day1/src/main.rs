@@ -0,0 +1,5 @@
+use {anyhow::Result, common::solution::run, y2021_day1::Day1};
+
+fn main() -> Result<()> {
+    run::<Day1>()
+}
@@ -1,51 +1,57 @@
 use {
     anyhow::{anyhow, Error, Result},
-    common::input::{from_path, inputs},
-    std::{convert::TryFrom, str::FromStr},
+    common::{input::lines_of, solution::Solution},
+    std::{convert::TryFrom, io::BufRead, str::FromStr},
 };
 
-fn main() -> Result<()> {
-    let inputs = inputs(to_syntax, from_path("day10/data/input.txt")?);
+pub struct Day10;
 
-    part1(&inputs)?;
-    part2(&inputs)?;
+impl Solution for Day10 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 10;
 
-    Ok(())
-}
+    type Input = Scores;
+    type Part1 = usize;
+    type Part2 = usize;
 
-fn part1(inputs: &[Syntax]) -> Result<()> {
-    println!(
-        "Day 10 Part 1 => {:?}",
-        inputs
-            .iter()
-            .filter_map(|s| match s {
-                Syntax::Corrupted(_) => Some(s.score()),
-                _ => None,
-            })
-            .sum::<usize>()
-    );
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        scores(reader)
+    }
 
-    Ok(())
-}
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        Ok(input.corrupted)
+    }
 
-fn part2(inputs: &[Syntax]) -> Result<()> {
-    let mut incomplete = inputs
-        .iter()
-        .filter_map(|s| match s {
-            Syntax::Incomplete(_) => Some(s.score()),
-            _ => None,
-        })
-        .collect::<Vec<usize>>();
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        let mut incomplete = input.incomplete.clone();
+        incomplete.sort_unstable();
 
-    incomplete.sort_unstable();
+        incomplete
+            .get(incomplete.len() / 2)
+            .copied()
+            .ok_or_else(|| anyhow!("No incomplete lines"))
+    }
+}
 
-    println!("Day 10 Part 2 => {}", incomplete[incomplete.len() / 2]);
+#[derive(Debug, Default, PartialEq)]
+pub struct Scores {
+    corrupted: usize,
+    incomplete: Vec<usize>,
+}
 
-    Ok(())
+pub fn scores<R: BufRead>(reader: R) -> Result<Scores> {
+    lines_of::<Syntax>(reader).try_fold(Scores::default(), |mut scores, syntax| {
+        match syntax? {
+            s @ Syntax::Corrupted(_) => scores.corrupted += s.score(),
+            s @ Syntax::Incomplete(_) => scores.incomplete.push(s.score()),
+            Syntax::Complete => (),
+        }
+        Ok(scores)
+    })
 }
 
 #[derive(Debug, PartialEq)]
-enum Bracket {
+pub enum Bracket {
     Round,
     Square,
     Curly,
@@ -77,7 +83,7 @@ impl TryFrom<char> for Chunk {
 }
 
 #[derive(Debug, PartialEq)]
-enum Syntax {
+pub enum Syntax {
     Complete,
     Incomplete(Vec<Bracket>),
     Corrupted(Bracket),
@@ -143,17 +149,22 @@ impl FromStr for Syntax {
     }
 }
 
-fn to_syntax(s: String) -> Option<Syntax> {
-    s.parse().ok()
-}
-
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use {
+        super::*,
+        common::{examples, fixture, input::try_inputs},
+        std::io::Cursor,
+    };
+
+    examples! {
+        Day10;
+        check_example: "test.txt" => { part1: 26397, part2: 288957 },
+    }
 
     #[test]
     fn check_inputs() -> Result<()> {
-        let inputs = inputs(to_syntax, from_path("test/test.txt")?);
+        let inputs: Vec<Syntax> = try_inputs(fixture!("test.txt")?)?;
 
         assert_eq!(inputs.len(), 10);
         assert_eq!(inputs.iter().filter(|s| s == &&Syntax::Complete).count(), 0);
@@ -177,7 +188,7 @@ mod tests {
 
     #[test]
     fn check_scores() -> Result<()> {
-        let inputs = inputs(to_syntax, from_path("test/test.txt")?);
+        let inputs: Vec<Syntax> = try_inputs(fixture!("test.txt")?)?;
 
         assert_eq!(
             inputs
@@ -204,6 +215,20 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn check_streamed_scores() -> Result<()> {
+        assert_eq!(
+            scores(fixture!("test.txt")?)?,
+            Scores {
+                corrupted: 26397,
+                incomplete: vec![288957, 5566, 1480781, 995444, 294],
+            }
+        );
+        assert!(scores(Cursor::new("(]\n(x")).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn check_parse_syntax() -> Result<()> {
         let s = "[<>({}){}[([])<>]]".to_string();
@@ -0,0 +1,5 @@
+use {anyhow::Result, common::solution::run, y2021_day10::Day10};
+
+fn main() -> Result<()> {
+    run::<Day10>()
+}
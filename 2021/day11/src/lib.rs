@@ -0,0 +1,229 @@
+use {
+    anyhow::Result,
+    common::{
+        grid::{Grid, Position},
+        solution::Solution,
+    },
+    std::io::BufRead,
+};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 11;
+
+    type Input = Grid<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Ok(Grid::digits(reader)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        let mut grid = Octopuses::new(input.clone());
+
+        Ok(grid.steps(100))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        let mut grid = Octopuses::new(input.clone());
+
+        Ok(grid.steps_until(100).unwrap_or(0))
+    }
+}
+
+#[derive(Debug)]
+struct Octopuses {
+    cells: Grid<u32>,
+}
+
+impl Octopuses {
+    fn new(cells: Grid<u32>) -> Self {
+        Self { cells }
+    }
+
+    fn step(&mut self) -> u32 {
+        for position in self.cells.positions() {
+            self.increment(position);
+        }
+        self.reset()
+    }
+
+    fn increment(&mut self, position: Position) {
+        if self.cells[position] < 10 {
+            self.cells[position] += 1;
+            if self.cells[position] == 10 {
+                self.flash(position);
+            }
+        }
+    }
+
+    fn flash(&mut self, position: Position) {
+        self.cells[position] = 10;
+
+        for neighbour in self.cells.adjacent(position) {
+            self.increment(neighbour);
+        }
+    }
+
+    fn reset(&mut self) -> u32 {
+        let mut count = 0u32;
+        for (_, cell) in self.cells.iter_mut() {
+            if *cell > 9 {
+                *cell = 0;
+                count += 1;
+            }
+        }
+        count
+    }
+
+    fn steps(&mut self, steps: u32) -> u32 {
+        (1..=steps).fold(0, |acc, _| acc + self.step())
+    }
+
+    fn steps_until(&mut self, count: u32) -> Option<u32> {
+        (1..).find(|_| self.step() == count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        common::{examples, fixture},
+    };
+
+    examples! {
+        Day11;
+        check_example: "test.txt" => { part1: 1656, part2: 195 },
+    }
+
+    #[test]
+    fn check_inputs() -> Result<()> {
+        let inputs = Grid::digits(fixture!("test.txt")?)?;
+
+        let grid = Octopuses::new(inputs);
+        assert_eq!(grid.cells.rows(), 10);
+        assert_eq!(grid.cells.columns(), 10);
+        assert_eq!(
+            grid.cells.row(0).copied().collect::<Vec<u32>>(),
+            vec![5, 4, 8, 3, 1, 4, 3, 2, 2, 3]
+        );
+
+        Ok(())
+    }
+
+    fn test_inputs() -> Grid<u32> {
+        cells(vec![
+            vec![1, 1, 1, 1, 1],
+            vec![1, 9, 9, 9, 1],
+            vec![1, 9, 1, 9, 1],
+            vec![1, 9, 9, 9, 1],
+            vec![1, 1, 1, 1, 1],
+        ])
+    }
+
+    fn cells(values: Vec<Vec<u32>>) -> Grid<u32> {
+        Grid::try_from(values).unwrap()
+    }
+
+    #[test]
+    fn check_grid() {
+        let inputs = test_inputs();
+        let grid = Octopuses::new(inputs);
+        assert_eq!(grid.cells.rows(), 5);
+        assert_eq!(grid.cells.columns(), 5);
+        assert_eq!(
+            grid.cells.row(0).copied().collect::<Vec<u32>>(),
+            vec![1, 1, 1, 1, 1]
+        );
+    }
+
+    #[test]
+    fn check_step() {
+        let inputs = test_inputs();
+        let mut grid = Octopuses::new(inputs);
+        assert_eq!(grid.step(), 9);
+
+        assert_eq!(
+            grid.cells,
+            cells(vec![
+                vec![3, 4, 5, 4, 3],
+                vec![4, 0, 0, 0, 4],
+                vec![5, 0, 0, 0, 5],
+                vec![4, 0, 0, 0, 4],
+                vec![3, 4, 5, 4, 3],
+            ])
+        );
+    }
+
+    #[test]
+    fn check_steps() {
+        let inputs = test_inputs();
+        let mut grid = Octopuses::new(inputs);
+        assert_eq!(grid.steps(2), 9);
+
+        assert_eq!(
+            grid.cells,
+            cells(vec![
+                vec![4, 5, 6, 5, 4],
+                vec![5, 1, 1, 1, 5],
+                vec![6, 1, 1, 1, 6],
+                vec![5, 1, 1, 1, 5],
+                vec![4, 5, 6, 5, 4],
+            ])
+        );
+    }
+
+    #[test]
+    fn check_input_steps() -> Result<()> {
+        let inputs = Grid::digits(fixture!("test.txt")?)?;
+
+        let mut grid = Octopuses::new(inputs);
+        assert_eq!(grid.steps(100), 1656);
+        assert_eq!(
+            grid.cells,
+            cells(vec![
+                vec![0, 3, 9, 7, 6, 6, 6, 8, 6, 6],
+                vec![0, 7, 4, 9, 7, 6, 6, 9, 1, 8],
+                vec![0, 0, 5, 3, 9, 7, 6, 9, 3, 3],
+                vec![0, 0, 0, 4, 2, 9, 7, 8, 2, 2],
+                vec![0, 0, 0, 4, 2, 2, 9, 8, 9, 2],
+                vec![0, 0, 5, 3, 2, 2, 2, 8, 7, 7],
+                vec![0, 5, 3, 2, 2, 2, 2, 9, 6, 6],
+                vec![9, 3, 2, 2, 2, 2, 8, 9, 6, 6],
+                vec![7, 9, 2, 2, 2, 8, 6, 8, 6, 6],
+                vec![6, 7, 8, 9, 9, 9, 8, 7, 6, 6],
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_input_steps_until() -> Result<()> {
+        let inputs = Grid::digits(fixture!("test.txt")?)?;
+
+        let mut grid = Octopuses::new(inputs);
+        assert_eq!(grid.steps_until(100), Some(195));
+        assert_eq!(
+            grid.cells,
+            cells(vec![
+                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+                vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            ])
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+use {anyhow::Result, common::solution::run, y2021_day11::Day11};
+
+fn main() -> Result<()> {
+    run::<Day11>()
+}
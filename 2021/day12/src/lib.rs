@@ -1,34 +1,40 @@
 use {
-    anyhow::Result,
+    anyhow::{anyhow, Result},
     common::{
-        convert::to_vec_u32,
-        input::{from_path, list},
+        graph::{Graph, Once, Twice, Visit},
+        solution::Solution,
     },
-    std::collections::{HashMap, HashSet},
+    std::io::BufRead,
 };
 
-fn main() -> Result<()> {
-    let inputs = list(to_vec_u32, from_path("day12/data/input.txt")?);
+pub struct Day12;
 
-    part1(&inputs)?;
-    part2(&inputs)?;
+impl Solution for Day12 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 12;
 
-    Ok(())
-}
+    type Input = Graph<String>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-fn part1(_inputs: &[Vec<u32>]) -> Result<()> {
-    println!("Day 12 Part 1 => {:?}", "");
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Ok(Graph::parse_edges(reader)?)
+    }
 
-    Ok(())
-}
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        let caves = Caves::from_graph(input);
 
-fn part2(_inputs: &[Vec<u32>]) -> Result<()> {
-    println!("Day 12 Part 2 => {}", "");
+        Ok(caves.routes().len())
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        let caves = Caves::from_graph(input);
+
+        caves.count(&Twice(Cave::is_small))
+    }
 }
 
-#[derive(Debug, PartialEq, PartialOrd, Eq, Hash, Clone, Copy)]
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Hash, Clone, Copy)]
 struct Cave<'a> {
     id: &'a str,
     small: bool,
@@ -49,7 +55,7 @@ impl<'a> Cave<'a> {
 struct Caves<'a> {
     start: Option<Cave<'a>>,
     end: Option<Cave<'a>>,
-    links: HashMap<Cave<'a>, HashSet<Cave<'a>>>,
+    graph: Graph<Cave<'a>>,
 }
 
 impl<'a> Caves<'a> {
@@ -63,29 +69,65 @@ impl<'a> Caves<'a> {
         caves
     }
 
+    fn from_graph(graph: &'a Graph<String>) -> Self {
+        Self::new(
+            graph
+                .arcs()
+                .map(|(from, to)| vec![Cave::new(from), Cave::new(to)])
+                .collect(),
+        )
+    }
+
     fn add(&mut self, cave: Cave<'a>, link: Cave<'a>) {
-        if self.start.is_none() && cave.id == "start".to_string() {
+        if self.start.is_none() && cave.id == "start" {
             self.start = Some(cave)
         }
-        if self.end.is_none() && cave.id == "end".to_string() {
+        if self.end.is_none() && cave.id == "end" {
             self.end = Some(cave)
         }
-        self.links.entry(cave).or_default().insert(link);
+        self.graph.add_arc(cave, link);
+    }
+
+    fn routes(&self) -> Vec<Vec<Cave<'a>>> {
+        self.paths(&Once(Cave::is_small))
     }
 
-    fn routes(&self) -> Vec<Vec<Cave<'_>>> {
-        vec![]
+    fn paths<V: Visit<Cave<'a>>>(&self, policy: &V) -> Vec<Vec<Cave<'a>>> {
+        let mut paths = match (&self.start, &self.end) {
+            (Some(start), Some(end)) => self.graph.paths(start, end, policy),
+            _ => vec![],
+        };
+        paths.sort();
+        paths
+    }
+
+    fn count<V: Visit<Cave<'a>>>(&self, policy: &V) -> Result<usize> {
+        match (&self.start, &self.end) {
+            (Some(start), Some(end)) => Ok(self.graph.count_paths(start, end, policy)),
+            _ => Err(anyhow!("Missing start or end cave")),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use {
+        super::*,
+        std::collections::{HashMap, HashSet},
+    };
+
+    fn links<'a>(caves: &Caves<'a>) -> HashMap<Cave<'a>, HashSet<Cave<'a>>> {
+        caves
+            .graph
+            .nodes()
+            .map(|cave| (*cave, caves.graph.neighbours(cave).copied().collect()))
+            .collect()
+    }
 
     #[test]
     fn check_cave() {
-        assert_eq!(Cave::new("aa").is_small(), true);
-        assert_eq!(Cave::new("AA").is_small(), false);
+        assert!(Cave::new("aa").is_small());
+        assert!(!Cave::new("AA").is_small());
     }
 
     #[test]
@@ -95,7 +137,7 @@ mod tests {
         assert_eq!(caves.start, None);
         assert_eq!(caves.end, Some(Cave::new("end")));
         assert_eq!(
-            caves.links,
+            links(&caves),
             HashMap::from([
                 (Cave::new("aa"), HashSet::from([Cave::new("end")])),
                 (Cave::new("end"), HashSet::from([Cave::new("aa")])),
@@ -117,7 +159,7 @@ mod tests {
     }
 
     fn test_caves<'a>(ids: &'a str) -> Vec<Cave<'a>> {
-        ids.split(',').map(|s| Cave::new(s)).collect()
+        ids.split(',').map(Cave::new).collect()
     }
 
     #[test]
@@ -126,9 +168,9 @@ mod tests {
 
         assert_eq!(caves.start, Some(Cave::new("start")));
         assert_eq!(caves.end, Some(Cave::new("end")));
-        println!("{:#?}", caves.links);
+        println!("{:#?}", caves.graph);
         assert_eq!(
-            caves.links,
+            links(&caves),
             HashMap::from([
                 (
                     Cave::new("start"),
@@ -184,4 +226,29 @@ mod tests {
             ]
         )
     }
+
+    #[test]
+    fn check_count() -> Result<()> {
+        let caves = test_input();
+
+        assert_eq!(caves.count(&Once(Cave::is_small))?, 10);
+        assert_eq!(caves.count(&Twice(Cave::is_small))?, 36);
+        assert_eq!(caves.paths(&Twice(Cave::is_small)).len(), 36);
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_from_graph() -> Result<()> {
+        let graph = Graph::parse_edges(std::io::Cursor::new(
+            "dc-end\nHN-start\nstart-kj\ndc-start\ndc-HN\nLN-dc\nHN-end\nkj-sa\nkj-HN\nkj-dc",
+        ))?;
+        let caves = Caves::from_graph(&graph);
+
+        assert_eq!(Day12::part1(&graph)?, 19);
+        assert_eq!(Day12::part2(&graph)?, 103);
+        assert_eq!(caves.routes().len(), 19);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,5 @@
+use {anyhow::Result, common::solution::run, y2021_day12::Day12};
+
+fn main() -> Result<()> {
+    run::<Day12>()
+}
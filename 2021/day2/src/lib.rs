@@ -0,0 +1,202 @@
+use {
+    anyhow::{anyhow, Error, Result},
+    common::{input::try_inputs, parse::parse, solution::Solution},
+    std::{convert::TryFrom, io::BufRead, str::FromStr},
+};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 2;
+
+    type Input = Vec<Direction>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Ok(try_inputs(reader)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        let mut submarine = Submarine::default();
+        submarine.steers(input.to_vec());
+
+        Ok(submarine.absolute())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        let mut submarine = Submarine::default();
+        submarine.adjustments(input.to_vec());
+
+        Ok(submarine.absolute())
+    }
+}
+
+#[derive(Debug, Default)]
+struct Submarine {
+    debug: bool,
+    horizontal: u32,
+    depth: u32,
+    aim: i32,
+}
+
+impl Submarine {
+    fn adjust(&mut self, direction: Direction) -> &mut Self {
+        match direction {
+            Direction::Forward(x) => {
+                self.horizontal += x;
+                self.depth = ((self.depth as i32) + (x as i32 * self.aim)) as u32;
+            }
+            Direction::Up(x) => self.aim -= x as i32,
+            Direction::Down(x) => self.aim += x as i32,
+        }
+
+        if self.debug {
+            println!("{:?} => {:?}", direction, self);
+        }
+
+        self
+    }
+
+    fn adjustments(&mut self, directions: Vec<Direction>) -> &mut Self {
+        for direction in directions {
+            self.adjust(direction);
+        }
+
+        self
+    }
+
+    fn steer(&mut self, direction: Direction) -> &mut Self {
+        match direction {
+            Direction::Forward(x) => self.horizontal += x,
+            Direction::Up(x) => self.depth -= x,
+            Direction::Down(x) => self.depth += x,
+        }
+
+        if self.debug {
+            println!("{:?} => {:?}", direction, self);
+        }
+
+        self
+    }
+
+    fn steers(&mut self, directions: Vec<Direction>) -> &mut Self {
+        for direction in directions {
+            self.steer(direction);
+        }
+
+        self
+    }
+
+    fn absolute(&self) -> u64 {
+        self.horizontal as u64 * self.depth as u64
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Direction {
+    Forward(u32),
+    Up(u32),
+    Down(u32),
+}
+
+impl TryFrom<String> for Direction {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self> {
+        Direction::try_from(&*s)
+    }
+}
+
+impl TryFrom<&str> for Direction {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self> {
+        let (direction, distance): (String, u32) = parse("{word} {u32}", s)?;
+        match direction.as_str() {
+            "forward" => Ok(Direction::Forward(distance)),
+            "up" => Ok(Direction::Up(distance)),
+            "down" => Ok(Direction::Down(distance)),
+            dir => Err(anyhow!("Invalid direction '{}'", dir)),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Direction::try_from(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {self::Direction::*, super::*};
+
+    #[test]
+    fn check_default() {
+        let sub = Submarine::default();
+        assert_eq!(sub.horizontal, 0);
+        assert_eq!(sub.depth, 0);
+        assert_eq!(sub.absolute(), 0);
+    }
+
+    #[test]
+    fn check_adjust() {
+        let mut sub = Submarine::default();
+        sub.adjust(Forward(5));
+        assert_eq!(sub.horizontal, 5);
+        assert_eq!(sub.depth, 0);
+        assert_eq!(sub.absolute(), 0);
+    }
+
+    #[test]
+    fn check_adjustments() {
+        let mut sub = Submarine::default();
+        sub.adjustments(vec![
+            Forward(5),
+            Down(5),
+            Forward(8),
+            Up(3),
+            Down(8),
+            Forward(2),
+        ]);
+        assert_eq!(sub.horizontal, 15);
+        assert_eq!(sub.depth, 60);
+        assert_eq!(sub.absolute(), 900);
+    }
+
+    #[test]
+    fn check_steers() {
+        let mut sub = Submarine::default();
+        sub.steers(vec![
+            Forward(5),
+            Down(5),
+            Forward(8),
+            Up(3),
+            Down(8),
+            Forward(2),
+        ]);
+        assert_eq!(sub.horizontal, 15);
+        assert_eq!(sub.depth, 10);
+        assert_eq!(sub.absolute(), 150);
+    }
+
+    #[test]
+    fn check_direction_success() {
+        assert_eq!("forward 100".parse::<Direction>().ok(), Some(Forward(100)));
+        assert_eq!("up 20".parse::<Direction>().ok(), Some(Up(20)));
+        assert_eq!("down 10".parse::<Direction>().ok(), Some(Down(10)));
+    }
+
+    #[test]
+    fn check_direction_failure() {
+        assert_eq!("  ".parse::<Direction>().ok(), None);
+        assert_eq!("10".parse::<Direction>().ok(), None);
+        assert_eq!("ABC 10".parse::<Direction>().ok(), None);
+        assert_eq!("forward".parse::<Direction>().ok(), None);
+        assert_eq!("forward ABC".parse::<Direction>().ok(), None);
+    }
+}
@@ -0,0 +1,5 @@
+use {anyhow::Result, common::solution::run, y2021_day2::Day2};
+
+fn main() -> Result<()> {
+    run::<Day2>()
+}
@@ -1,9 +1,10 @@
 use {
-    anyhow::{Error, Result},
-    common::input::{from_path, inputs},
+    anyhow::{anyhow, Error, Result},
+    common::{convert::from_binary, input::try_inputs, solution::Solution},
     std::{
         convert::TryFrom,
         fmt::{self, Binary, Formatter, Write},
+        io::BufRead,
         num::ParseIntError,
         ops::AddAssign,
         result,
@@ -11,40 +12,42 @@ use {
     },
 };
 
-fn main() -> Result<()> {
-    let inputs = inputs(to_diagnostic, from_path("day3/data/input.txt")?);
+pub struct Day3;
 
-    part1(&inputs)?;
-    part2(&inputs)?;
+impl Solution for Day3 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 3;
 
-    Ok(())
-}
-
-fn part1(inputs: &[Diagnostic]) -> Result<()> {
-    let summary = Summary::summarize(inputs);
+    type Input = Vec<Diagnostic>;
+    type Part1 = u32;
+    type Part2 = u32;
 
-    let gamma: u32 = summary.gamma().try_into()?;
-    let epsilon: u32 = summary.epsilon().try_into()?;
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Ok(try_inputs(reader)?)
+    }
 
-    println!(" Day 3 Part 1 => {}", gamma * epsilon);
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        let summary = Summary::summarize(input);
 
-    Ok(())
-}
+        let gamma: u32 = summary.gamma().try_into()?;
+        let epsilon: u32 = summary.epsilon().try_into()?;
 
-fn part2(inputs: &[Diagnostic]) -> Result<()> {
-    let rating_high = Summary::rating(inputs, vec![], true);
-    let rating_low = Summary::rating(inputs, vec![], false);
+        Ok(gamma * epsilon)
+    }
 
-    let oxygen: u32 = rating_high.try_into()?;
-    let co2: u32 = rating_low.try_into()?;
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        let rating_high = Summary::rating(input, vec![], true);
+        let rating_low = Summary::rating(input, vec![], false);
 
-    println!("Day 3 Part 2 => {}", oxygen * co2);
+        let oxygen: u32 = rating_high.try_into()?;
+        let co2: u32 = rating_low.try_into()?;
 
-    Ok(())
+        Ok(oxygen * co2)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
-struct Diagnostic {
+pub struct Diagnostic {
     values: Vec<bool>,
 }
 
@@ -103,7 +106,14 @@ impl TryFrom<&str> for Diagnostic {
     type Error = Error;
 
     fn try_from(s: &str) -> Result<Self> {
-        let v: Vec<bool> = s.chars().map(|c| c == '1').collect();
+        let v = s
+            .chars()
+            .map(|c| match c {
+                '0' => Ok(false),
+                '1' => Ok(true),
+                c => Err(anyhow!("Invalid bit '{}'", c)),
+            })
+            .collect::<Result<Vec<bool>>>()?;
         Ok(Diagnostic::new(&v))
     }
 }
@@ -128,14 +138,10 @@ impl TryFrom<&Diagnostic> for u32 {
     type Error = ParseIntError;
 
     fn try_from(d: &Diagnostic) -> result::Result<Self, Self::Error> {
-        u32::from_str_radix(&*format!("{:b}", d), 2)
+        from_binary(&format!("{:b}", d))
     }
 }
 
-fn to_diagnostic(s: String) -> Option<Diagnostic> {
-    s.parse().ok()
-}
-
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 struct Summary {
     count: u32,
@@ -170,8 +176,8 @@ impl Summary {
             criteria.push(diagnostic.values[position]);
             let filtered: Vec<Diagnostic> = inputs
                 .iter()
+                .filter(|&v| v.matches(&criteria))
                 .cloned()
-                .filter(|v| v.matches(&criteria))
                 .collect();
 
             Self::rating(&filtered, criteria, high)
@@ -186,7 +192,7 @@ impl Summary {
     fn gamma(&self) -> Diagnostic {
         let mut gamma = Vec::new();
         gamma.resize(self.totals.len(), false);
-        let half = (self.count + 1) / 2;
+        let half = self.count.div_ceil(2);
 
         for (i, total) in self.totals.iter().enumerate() {
             if *total >= half {
@@ -230,7 +236,7 @@ mod tests {
 
     #[test]
     fn check_add_assign() {
-        let diagnostic = Diagnostic::new(&vec![true, false, false, true, false]);
+        let diagnostic = Diagnostic::new(&[true, false, false, true, false]);
         let mut summary = Summary::new();
         summary += diagnostic;
         assert_eq!(summary.count, 1);
@@ -239,7 +245,7 @@ mod tests {
 
     #[test]
     fn check_gamma() {
-        let diagnostic = Diagnostic::new(&vec![true, false, false, true, false]);
+        let diagnostic = Diagnostic::new(&[true, false, false, true, false]);
         let mut summary = Summary::new();
         summary += diagnostic;
         assert_eq!(
@@ -254,19 +260,19 @@ mod tests {
 
     #[test]
     fn check_diagnostics() -> Result<()> {
-        let summary = Summary::summarize(&vec![
-            Diagnostic::new(&vec![false, false, true, false, false]),
-            Diagnostic::new(&vec![true, true, true, true, false]),
-            Diagnostic::new(&vec![true, false, true, true, false]),
-            Diagnostic::new(&vec![true, false, true, true, true]),
-            Diagnostic::new(&vec![true, false, true, false, true]),
-            Diagnostic::new(&vec![false, true, true, true, true]),
-            Diagnostic::new(&vec![false, false, true, true, true]),
-            Diagnostic::new(&vec![true, true, true, false, false]),
-            Diagnostic::new(&vec![true, false, false, false, false]),
-            Diagnostic::new(&vec![true, true, false, false, true]),
-            Diagnostic::new(&vec![false, false, false, true, false]),
-            Diagnostic::new(&vec![false, true, false, true, false]),
+        let summary = Summary::summarize(&[
+            Diagnostic::new(&[false, false, true, false, false]),
+            Diagnostic::new(&[true, true, true, true, false]),
+            Diagnostic::new(&[true, false, true, true, false]),
+            Diagnostic::new(&[true, false, true, true, true]),
+            Diagnostic::new(&[true, false, true, false, true]),
+            Diagnostic::new(&[false, true, true, true, true]),
+            Diagnostic::new(&[false, false, true, true, true]),
+            Diagnostic::new(&[true, true, true, false, false]),
+            Diagnostic::new(&[true, false, false, false, false]),
+            Diagnostic::new(&[true, true, false, false, true]),
+            Diagnostic::new(&[false, false, false, true, false]),
+            Diagnostic::new(&[false, true, false, true, false]),
         ]);
 
         let gamma = &summary.gamma();
@@ -289,6 +295,11 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn check_parse_failure() {
+        assert!("01201".parse::<Diagnostic>().is_err());
+    }
+
     #[test]
     fn check_binary() -> Result<()> {
         let d: Diagnostic = "01001".parse()?;
@@ -312,28 +323,28 @@ mod tests {
     fn check_matches() {
         let d: Diagnostic = "01001".parse().unwrap();
 
-        assert_eq!(d.matches(&vec![]), true);
-        assert_eq!(d.matches(&vec![false]), true);
-        assert_eq!(d.matches(&vec![true]), false);
-        assert_eq!(d.matches(&vec![false, true]), true);
-        assert_eq!(d.matches(&vec![false, false]), false);
+        assert!(d.matches(&[]));
+        assert!(d.matches(&[false]));
+        assert!(!d.matches(&[true]));
+        assert!(d.matches(&[false, true]));
+        assert!(!d.matches(&[false, false]));
     }
 
     #[test]
     fn check_rating_high() {
         let inputs = vec![
-            Diagnostic::new(&vec![false, false, true, false, false]),
-            Diagnostic::new(&vec![true, true, true, true, false]),
-            Diagnostic::new(&vec![true, false, true, true, false]),
-            Diagnostic::new(&vec![true, false, true, true, true]),
-            Diagnostic::new(&vec![true, false, true, false, true]),
-            Diagnostic::new(&vec![false, true, true, true, true]),
-            Diagnostic::new(&vec![false, false, true, true, true]),
-            Diagnostic::new(&vec![true, true, true, false, false]),
-            Diagnostic::new(&vec![true, false, false, false, false]),
-            Diagnostic::new(&vec![true, true, false, false, true]),
-            Diagnostic::new(&vec![false, false, false, true, false]),
-            Diagnostic::new(&vec![false, true, false, true, false]),
+            Diagnostic::new(&[false, false, true, false, false]),
+            Diagnostic::new(&[true, true, true, true, false]),
+            Diagnostic::new(&[true, false, true, true, false]),
+            Diagnostic::new(&[true, false, true, true, true]),
+            Diagnostic::new(&[true, false, true, false, true]),
+            Diagnostic::new(&[false, true, true, true, true]),
+            Diagnostic::new(&[false, false, true, true, true]),
+            Diagnostic::new(&[true, true, true, false, false]),
+            Diagnostic::new(&[true, false, false, false, false]),
+            Diagnostic::new(&[true, true, false, false, true]),
+            Diagnostic::new(&[false, false, false, true, false]),
+            Diagnostic::new(&[false, true, false, true, false]),
         ];
         let rating = Summary::rating(&inputs, vec![], true);
 
@@ -343,18 +354,18 @@ mod tests {
     #[test]
     fn check_rating_low() {
         let inputs = vec![
-            Diagnostic::new(&vec![false, false, true, false, false]),
-            Diagnostic::new(&vec![true, true, true, true, false]),
-            Diagnostic::new(&vec![true, false, true, true, false]),
-            Diagnostic::new(&vec![true, false, true, true, true]),
-            Diagnostic::new(&vec![true, false, true, false, true]),
-            Diagnostic::new(&vec![false, true, true, true, true]),
-            Diagnostic::new(&vec![false, false, true, true, true]),
-            Diagnostic::new(&vec![true, true, true, false, false]),
-            Diagnostic::new(&vec![true, false, false, false, false]),
-            Diagnostic::new(&vec![true, true, false, false, true]),
-            Diagnostic::new(&vec![false, false, false, true, false]),
-            Diagnostic::new(&vec![false, true, false, true, false]),
+            Diagnostic::new(&[false, false, true, false, false]),
+            Diagnostic::new(&[true, true, true, true, false]),
+            Diagnostic::new(&[true, false, true, true, false]),
+            Diagnostic::new(&[true, false, true, true, true]),
+            Diagnostic::new(&[true, false, true, false, true]),
+            Diagnostic::new(&[false, true, true, true, true]),
+            Diagnostic::new(&[false, false, true, true, true]),
+            Diagnostic::new(&[true, true, true, false, false]),
+            Diagnostic::new(&[true, false, false, false, false]),
+            Diagnostic::new(&[true, true, false, false, true]),
+            Diagnostic::new(&[false, false, false, true, false]),
+            Diagnostic::new(&[false, true, false, true, false]),
         ];
         let rating = Summary::rating(&inputs, vec![], false);
 
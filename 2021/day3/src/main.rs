@@ -0,0 +1,5 @@
+use {anyhow::Result, common::solution::run, y2021_day3::Day3};
+
+fn main() -> Result<()> {
+    run::<Day3>()
+}
@@ -1,62 +1,56 @@
 use {
-    anyhow::Result,
-    common::{
-        convert::{to_u32, to_vec},
-        input::{batch, from_path, list},
-    },
-    std::collections::HashMap,
+    anyhow::{anyhow, Result},
+    common::{convert::try_to_vec, error::ParseError, input::sections, solution::Solution},
+    std::{collections::HashMap, io::BufRead},
 };
 
-fn main() -> Result<()> {
-    let inputs = list(to_u32, from_path("day4/data/input.txt")?);
-
-    part1(&inputs)?;
-    part2(&inputs)?;
-
-    Ok(())
-}
-
-fn part1(inputs: &[u32]) -> Result<()> {
-    let boards = &mut boards_from_path("day4/data/boards.txt")?;
-
-    if let Some((number, board)) = play(inputs, boards) {
-        println!(
-            "Day 4 Part 1 => {}",
-            number * board.unmarked().iter().copied().sum::<u32>()
-        );
-    } else {
-        println!("Day 4 Part 1 => No winning board!");
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 4;
+
+    type Input = Bingo;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        let mut sections = sections(reader);
+        let numbers = sections
+            .next()
+            .ok_or_else(|| anyhow!("Missing numbers"))??
+            .list()?;
+        let boards = sections
+            .map(|section| {
+                let rows = section?.parse_with(|s| try_to_vec(&s, ' '))?;
+                Ok(Board::new(&rows))
+            })
+            .collect::<std::result::Result<Vec<Board>, ParseError>>()?;
+
+        Ok(Bingo { numbers, boards })
     }
-    Ok(())
-}
 
-fn part2(inputs: &[u32]) -> Result<()> {
-    let boards = &mut boards_from_path("day4/data/boards.txt")?;
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        let boards = &mut input.boards.to_vec();
 
-    if let Some((number, board)) = play_last(inputs, boards) {
-        println!(
-            "Day 4 Part 2 => {}",
-            number * board.unmarked().iter().copied().sum::<u32>()
-        );
-    } else {
-        println!("Day 4 Part 2 => No winning board!");
+        play(&input.numbers, boards)
+            .map(|(number, board)| number * board.unmarked().iter().copied().sum::<u32>())
+            .ok_or_else(|| anyhow!("No winning board!"))
     }
-    Ok(())
-}
 
-fn to_vec_u32(s: String) -> Option<Vec<u32>> {
-    if s.is_empty() {
-        None
-    } else {
-        Some(to_vec(s, ' ', to_u32))
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        let boards = &mut input.boards.to_vec();
+
+        play_last(&input.numbers, boards)
+            .map(|(number, board)| number * board.unmarked().iter().copied().sum::<u32>())
+            .ok_or_else(|| anyhow!("No winning board!"))
     }
 }
 
-fn boards_from_path(path: &str) -> Result<Vec<Board>> {
-    Ok(batch(to_vec_u32, |v| v.is_none(), from_path(path)?)
-        .iter()
-        .map(|v| Board::new(v))
-        .collect())
+#[derive(Debug)]
+pub struct Bingo {
+    numbers: Vec<u32>,
+    boards: Vec<Board>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -81,7 +75,7 @@ impl Number {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-struct Board {
+pub struct Board {
     numbers: HashMap<u32, Number>,
     rows: Vec<usize>,
     columns: Vec<usize>,
@@ -178,11 +172,19 @@ fn play_last(numbers: &[u32], boards: &mut [Board]) -> Option<(u32, Board)> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use {
+        super::*,
+        common::{examples, fixture},
+    };
+
+    examples! {
+        Day4;
+        check_example: "test.txt" => { part1: 4512, part2: 1924 },
+    }
 
     #[test]
     fn check_fill() {
-        let board = Board::new(&vec![
+        let board = Board::new(&[
             vec![22, 13, 17, 11, 0],
             vec![8, 2, 23, 4, 24],
             vec![21, 9, 14, 16, 7],
@@ -197,7 +199,7 @@ mod tests {
 
     #[test]
     fn check_draw() {
-        let mut board = Board::new(&vec![
+        let mut board = Board::new(&[
             vec![22, 13, 17, 11, 0],
             vec![8, 2, 23, 4, 24],
             vec![21, 9, 14, 16, 7],
@@ -206,7 +208,7 @@ mod tests {
         ]);
         assert_eq!(board.draw(&30), None);
         assert_eq!(board.draw(&13), Some(false));
-        assert_eq!(board.numbers.get(&13).unwrap().marked, true);
+        assert!(board.numbers.get(&13).unwrap().marked);
         assert_eq!(board.draw(&2), Some(false));
         assert_eq!(board.draw(&9), Some(false));
         assert_eq!(board.draw(&10), Some(false));
@@ -216,7 +218,7 @@ mod tests {
 
     #[test]
     fn check_winning_board() {
-        let mut board = Board::new(&vec![
+        let mut board = Board::new(&[
             vec![14, 21, 17, 24, 4],
             vec![10, 16, 15, 9, 19],
             vec![18, 8, 23, 26, 20],
@@ -244,12 +246,12 @@ mod tests {
                 (24, Some(true)),
             ]
         );
-        assert_eq!(board.unmarked().iter().map(|n| *n).sum::<u32>(), 188);
+        assert_eq!(board.unmarked().iter().copied().sum::<u32>(), 188);
     }
 
     #[test]
     fn check_loosing_board() {
-        let mut board = Board::new(&vec![
+        let mut board = Board::new(&[
             vec![22, 13, 17, 11, 0],
             vec![8, 2, 23, 4, 24],
             vec![21, 9, 14, 16, 7],
@@ -277,27 +279,27 @@ mod tests {
                 (24, Some(false)),
             ]
         );
-        assert_eq!(board.unmarked().iter().map(|n| *n).sum::<u32>(), 163);
+        assert_eq!(board.unmarked().iter().copied().sum::<u32>(), 163);
     }
 
     #[test]
     fn check_play() {
-        let boards = &mut vec![
-            Board::new(&vec![
+        let boards = &mut [
+            Board::new(&[
                 vec![22, 13, 17, 11, 0],
                 vec![8, 2, 23, 4, 24],
                 vec![21, 9, 14, 16, 7],
                 vec![6, 10, 3, 18, 5],
                 vec![1, 12, 20, 15, 19],
             ]),
-            Board::new(&vec![
+            Board::new(&[
                 vec![3, 15, 0, 2, 22],
                 vec![9, 18, 13, 17, 5],
                 vec![19, 8, 7, 25, 23],
                 vec![20, 11, 10, 24, 4],
                 vec![14, 21, 16, 12, 6],
             ]),
-            Board::new(&vec![
+            Board::new(&[
                 vec![14, 21, 17, 24, 4],
                 vec![10, 16, 15, 9, 19],
                 vec![18, 8, 23, 26, 20],
@@ -313,29 +315,29 @@ mod tests {
 
         assert!(matches!(result, Some((24, _))));
         assert_eq!(
-            result.unwrap().1.unmarked().iter().map(|n| *n).sum::<u32>(),
+            result.unwrap().1.unmarked().iter().copied().sum::<u32>(),
             188
         );
     }
 
     #[test]
     fn check_play_fail() {
-        let boards = &mut vec![
-            Board::new(&vec![
+        let boards = &mut [
+            Board::new(&[
                 vec![22, 13, 17, 11, 0],
                 vec![8, 2, 23, 4, 24],
                 vec![21, 9, 14, 16, 7],
                 vec![6, 10, 3, 18, 5],
                 vec![1, 12, 20, 15, 19],
             ]),
-            Board::new(&vec![
+            Board::new(&[
                 vec![3, 15, 0, 2, 22],
                 vec![9, 18, 13, 17, 5],
                 vec![19, 8, 7, 25, 23],
                 vec![20, 11, 10, 24, 4],
                 vec![14, 21, 16, 12, 6],
             ]),
-            Board::new(&vec![
+            Board::new(&[
                 vec![14, 21, 17, 29, 4],
                 vec![10, 16, 15, 9, 19],
                 vec![18, 8, 23, 26, 20],
@@ -343,30 +345,30 @@ mod tests {
                 vec![2, 0, 12, 3, 7],
             ]),
         ];
-        let numbers = &vec![7, 4, 9, 5, 11, 17, 23, 2, 0, 14, 21, 24];
+        let numbers = &[7, 4, 9, 5, 11, 17, 23, 2, 0, 14, 21, 24];
         let result = play(numbers, boards);
 
-        assert!(matches!(result, None));
+        assert!(result.is_none());
     }
 
     #[test]
     fn check_play_last() {
-        let boards = &mut vec![
-            Board::new(&vec![
+        let boards = &mut [
+            Board::new(&[
                 vec![22, 13, 17, 11, 0],
                 vec![8, 2, 23, 4, 24],
                 vec![21, 9, 14, 16, 7],
                 vec![6, 10, 3, 18, 5],
                 vec![1, 12, 20, 15, 19],
             ]),
-            Board::new(&vec![
+            Board::new(&[
                 vec![3, 15, 0, 2, 22],
                 vec![9, 18, 13, 17, 5],
                 vec![19, 8, 7, 25, 23],
                 vec![20, 11, 10, 24, 4],
                 vec![14, 21, 16, 12, 6],
             ]),
-            Board::new(&vec![
+            Board::new(&[
                 vec![14, 21, 17, 24, 4],
                 vec![10, 16, 15, 9, 19],
                 vec![18, 8, 23, 26, 20],
@@ -383,15 +385,25 @@ mod tests {
 
         assert!(matches!(result, Some((13, _))));
         assert_eq!(
-            result.unwrap().1.unmarked().iter().map(|n| *n).sum::<u32>(),
+            result.unwrap().1.unmarked().iter().copied().sum::<u32>(),
             148
         );
     }
 
     #[test]
-    fn check_boards_from_path() -> Result<()> {
-        let boards = boards_from_path("test/boards.txt")?;
-        assert_eq!(boards.len(), 3);
+    fn check_parse() -> Result<()> {
+        let bingo = Day4::parse(fixture!("test.txt")?)?;
+        assert_eq!(bingo.numbers.len(), 27);
+        assert_eq!(bingo.boards.len(), 3);
         Ok(())
     }
+
+    #[test]
+    fn check_parse_failure() {
+        let error = Day4::parse(std::io::Cursor::new("1,2\n\n1 2\n3 x"))
+            .unwrap_err()
+            .downcast::<ParseError>()
+            .unwrap();
+        assert_eq!((error.line, error.column), (4, 1));
+    }
 }
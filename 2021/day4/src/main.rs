@@ -0,0 +1,5 @@
+use {anyhow::Result, common::solution::run, y2021_day4::Day4};
+
+fn main() -> Result<()> {
+    run::<Day4>()
+}
@@ -0,0 +1,589 @@
+use {
+    anyhow::{anyhow, Error, Result},
+    common::{
+        geom::{Bounds2, Point2, Raster, Segment},
+        input::try_inputs,
+        parse::parse,
+        solution::Solution,
+    },
+    std::{
+        collections::{HashMap, HashSet},
+        io::BufRead,
+        str::FromStr,
+    },
+};
+
+mod sweep;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 5;
+
+    type Input = Vec<Line>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Ok(try_inputs(reader)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        overlaps(input, false, Mode::Raster)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        overlaps(input, true, Mode::Raster)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line(Segment);
+
+impl Line {
+    fn coords(startx: i64, starty: i64, endx: i64, endy: i64) -> Self {
+        Self(Segment::new(
+            Point2::new(startx, starty),
+            Point2::new(endx, endy),
+        ))
+    }
+
+    fn is_axis_aligned(&self) -> bool {
+        self.0.is_horizontal() || self.0.is_vertical()
+    }
+
+    fn points(&self) -> Raster {
+        self.0.raster()
+    }
+}
+
+impl FromStr for Line {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (x1, y1, x2, y2) = parse("{x1},{y1} -> {x2},{y2}", s)?;
+        Ok(Line::coords(x1, y1, x2, y2))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Raster,
+    Exact,
+    Sweep,
+}
+
+pub fn overlaps(lines: &[Line], allow_diagonal: bool, mode: Mode) -> Result<usize> {
+    let selected = || {
+        lines
+            .iter()
+            .filter(move |l| allow_diagonal || l.is_axis_aligned())
+    };
+
+    Ok(match mode {
+        Mode::Raster => {
+            let mut grid = Grid::for_lines(lines);
+            lines.iter().for_each(|l| grid.plot(l, allow_diagonal));
+            grid.points(2).count()
+        }
+        Mode::Exact => intersections(&selected().collect::<Vec<&Line>>()).len(),
+        Mode::Sweep => {
+            let segments = selected().map(|l| l.0).collect::<Vec<Segment>>();
+            sweep::count(&segments, 2).map_err(|s| {
+                anyhow!(
+                    "Line {},{} -> {},{} is not horizontal, vertical or diagonal",
+                    s.start.x,
+                    s.start.y,
+                    s.end.x,
+                    s.end.y
+                )
+            })?
+        }
+    })
+}
+
+fn intersections(lines: &[&Line]) -> HashSet<Point2> {
+    let mut points = HashSet::new();
+    for (i, a) in lines.iter().enumerate() {
+        for b in &lines[i + 1..] {
+            if let Some(overlap) = a.0.intersection(&b.0) {
+                points.extend(overlap.points());
+            }
+        }
+    }
+    points
+}
+
+const MAX_DENSE_CELLS: i64 = 1 << 24;
+
+#[derive(Debug)]
+enum Counts {
+    Dense { bounds: Bounds2, cells: Vec<u16> },
+    Sparse(HashMap<Point2, u32>),
+}
+
+#[derive(Debug)]
+struct Grid {
+    counts: Counts,
+}
+
+impl Default for Grid {
+    fn default() -> Self {
+        Self {
+            counts: Counts::Sparse(HashMap::new()),
+        }
+    }
+}
+
+impl Grid {
+    fn dense(bounds: Bounds2, area: usize) -> Self {
+        Self {
+            counts: Counts::Dense {
+                bounds,
+                cells: vec![0; area],
+            },
+        }
+    }
+
+    fn for_lines(lines: &[Line]) -> Self {
+        let bounds = Bounds2::of(lines.iter().flat_map(|l| [l.0.start, l.0.end]));
+        match bounds.and_then(|b| Some((b, b.area()?))) {
+            Some((bounds, area)) if area <= MAX_DENSE_CELLS => Self::dense(bounds, area as usize),
+            _ => Self::default(),
+        }
+    }
+
+    fn plot(&mut self, line: &Line, allow_diagonal: bool) {
+        if allow_diagonal || line.is_axis_aligned() {
+            for p in line.points() {
+                self.increment(p);
+            }
+        }
+    }
+
+    fn increment(&mut self, point: Point2) {
+        if let Counts::Dense { bounds, cells } = &self.counts {
+            if bounds.index(point).is_none_or(|i| cells[i] == u16::MAX) {
+                self.counts = Counts::Sparse(self.counts().collect());
+            }
+        }
+        match &mut self.counts {
+            Counts::Dense { bounds, cells } => {
+                if let Some(i) = bounds.index(point) {
+                    cells[i] += 1;
+                }
+            }
+            Counts::Sparse(points) => *points.entry(point).or_default() += 1,
+        }
+    }
+
+    fn counts(&self) -> Box<dyn Iterator<Item = (Point2, u32)> + '_> {
+        match &self.counts {
+            Counts::Dense { bounds, cells } => Box::new(
+                bounds
+                    .points()
+                    .zip(cells.iter())
+                    .filter(|(_, &count)| count > 0)
+                    .map(|(p, &count)| (p, count as u32)),
+            ),
+            Counts::Sparse(points) => Box::new(points.iter().map(|(&p, &count)| (p, count))),
+        }
+    }
+
+    fn points(&self, threshold: u32) -> impl Iterator<Item = Point2> + '_ {
+        self.counts()
+            .filter_map(move |(p, count)| (count >= threshold).then_some(p))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        common::{examples, fixture},
+    };
+
+    examples! {
+        Day5;
+        check_example: "test.txt" => { part1: 5, part2: 12 },
+    }
+
+    #[test]
+    fn check_point_fromstr() {
+        assert_eq!(" 10,2 ".parse::<Point2>().unwrap(), Point2::new(10, 2))
+    }
+
+    #[test]
+    fn check_line_from_str() {
+        assert_eq!(
+            "0,9 -> 5,9".parse::<Line>().unwrap(),
+            Line::coords(0, 9, 5, 9)
+        )
+    }
+
+    #[test]
+    fn check_line_from_str_failure() {
+        assert!("0,9 -> 5".parse::<Line>().is_err());
+        assert_eq!(
+            "0,9 -> 5,x".parse::<Line>().unwrap_err().to_string(),
+            "invalid {y2} 'x': invalid digit found in string at column 10"
+        );
+        assert_eq!("5,9".parse::<Point2>().unwrap(), Point2::new(5, 9));
+        assert!("5".parse::<Point2>().is_err());
+    }
+
+    #[test]
+    fn check_axis_aligned() {
+        assert!(Line::coords(0, 0, 0, 5).is_axis_aligned());
+        assert!(Line::coords(5, 0, 0, 0).is_axis_aligned());
+        assert!(!Line::coords(0, 0, 5, 5).is_axis_aligned());
+        assert!(!Line::coords(0, 0, 2, 5).is_axis_aligned());
+    }
+
+    #[test]
+    fn check_point_iter_up() {
+        assert_eq!(
+            Line::coords(0, 0, 0, 5).points().collect::<Vec<Point2>>(),
+            vec![
+                Point2::new(0, 0),
+                Point2::new(0, 1),
+                Point2::new(0, 2),
+                Point2::new(0, 3),
+                Point2::new(0, 4),
+                Point2::new(0, 5)
+            ]
+        );
+    }
+
+    #[test]
+    fn check_point_iter_across() {
+        assert_eq!(
+            Line::coords(0, 0, 3, 0).points().collect::<Vec<Point2>>(),
+            vec![
+                Point2::new(0, 0),
+                Point2::new(1, 0),
+                Point2::new(2, 0),
+                Point2::new(3, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_point_iter_diagonal() {
+        assert_eq!(
+            Line::coords(0, 0, 2, 5).points().collect::<Vec<Point2>>(),
+            vec![
+                Point2::new(0, 0),
+                Point2::new(0, 1),
+                Point2::new(1, 2),
+                Point2::new(1, 3),
+                Point2::new(2, 4),
+                Point2::new(2, 5)
+            ]
+        );
+    }
+
+    #[test]
+    fn check_point_iter_backwards() {
+        assert_eq!(
+            Line::coords(0, 5, 0, 0).points().collect::<Vec<Point2>>(),
+            vec![
+                Point2::new(0, 5),
+                Point2::new(0, 4),
+                Point2::new(0, 3),
+                Point2::new(0, 2),
+                Point2::new(0, 1),
+                Point2::new(0, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn check_grid_plot() {
+        let mut grid = Grid::default();
+        grid.plot(&Line::coords(0, 9, 5, 9), false);
+        assert_eq!(grid.counts().count(), 6);
+        let mut points = grid.counts().collect::<Vec<(Point2, u32)>>();
+        points.sort();
+        assert_eq!(
+            points,
+            vec![
+                (Point2::new(0, 9), 1),
+                (Point2::new(1, 9), 1),
+                (Point2::new(2, 9), 1),
+                (Point2::new(3, 9), 1),
+                (Point2::new(4, 9), 1),
+                (Point2::new(5, 9), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn check_grid_plot_diagonal() {
+        let mut grid = Grid::default();
+        grid.plot(&Line::coords(0, 4, 5, 9), false);
+        assert_eq!(grid.counts().count(), 0);
+    }
+
+    #[test]
+    fn check_grid_plot_diagonal_allowed() {
+        let mut grid = Grid::default();
+        grid.plot(&Line::coords(0, 4, 5, 9), true);
+        assert_eq!(grid.counts().count(), 6);
+        let mut points = grid.counts().collect::<Vec<(Point2, u32)>>();
+        points.sort();
+        assert_eq!(
+            points,
+            vec![
+                (Point2::new(0, 4), 1),
+                (Point2::new(1, 5), 1),
+                (Point2::new(2, 6), 1),
+                (Point2::new(3, 7), 1),
+                (Point2::new(4, 8), 1),
+                (Point2::new(5, 9), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn check_grid_plot_backwards() {
+        let mut grid = Grid::default();
+        grid.plot(&Line::coords(5, 9, 0, 9), false);
+        assert_eq!(grid.counts().count(), 6);
+        let mut points = grid.counts().collect::<Vec<(Point2, u32)>>();
+        points.sort();
+        assert_eq!(
+            points,
+            vec![
+                (Point2::new(0, 9), 1),
+                (Point2::new(1, 9), 1),
+                (Point2::new(2, 9), 1),
+                (Point2::new(3, 9), 1),
+                (Point2::new(4, 9), 1),
+                (Point2::new(5, 9), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn check_grid_points() {
+        let mut grid = Grid::default();
+        let lines = vec![
+            Line::coords(0, 9, 5, 9),
+            Line::coords(8, 0, 0, 8),
+            Line::coords(9, 4, 3, 4),
+            Line::coords(2, 2, 2, 1),
+            Line::coords(7, 0, 7, 4),
+            Line::coords(6, 4, 2, 0),
+            Line::coords(0, 9, 2, 9),
+            Line::coords(3, 4, 1, 4),
+            Line::coords(0, 0, 8, 8),
+            Line::coords(5, 5, 8, 2),
+        ];
+        lines.iter().for_each(|l| grid.plot(l, false));
+        let mut points = grid.points(2).collect::<Vec<Point2>>();
+        points.sort();
+        assert_eq!(points.len(), 5);
+        assert_eq!(
+            points,
+            vec![
+                Point2::new(0, 9),
+                Point2::new(1, 9),
+                Point2::new(2, 9),
+                Point2::new(3, 4),
+                Point2::new(7, 4)
+            ]
+        );
+    }
+
+    #[test]
+    fn check_grid_plot_any_slope() {
+        let mut grid = Grid::default();
+        grid.plot(&Line::coords(0, 0, 2, 5), false);
+        assert_eq!(grid.counts().count(), 0);
+        grid.plot(&Line::coords(0, 0, 2, 5), true);
+        assert_eq!(grid.counts().count(), 6);
+    }
+
+    #[test]
+    fn check_overlaps() -> Result<()> {
+        let lines: Vec<Line> = try_inputs(fixture!("test.txt")?)?;
+
+        for mode in [Mode::Raster, Mode::Exact, Mode::Sweep] {
+            assert_eq!(overlaps(&lines, false, mode)?, 5);
+            assert_eq!(overlaps(&lines, true, mode)?, 12);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_overlaps_any_slope() -> Result<()> {
+        let lines = vec![
+            Line::coords(0, 0, 2, 5),
+            Line::coords(1, 0, 1, 5),
+            Line::coords(0, 0, 4, 6),
+            Line::coords(0, 3, 4, 3),
+        ];
+
+        assert_eq!(overlaps(&lines, true, Mode::Raster)?, 5);
+        assert_eq!(overlaps(&lines, true, Mode::Exact)?, 3);
+        assert_eq!(
+            overlaps(&lines, true, Mode::Sweep).unwrap_err().to_string(),
+            "Line 0,0 -> 2,5 is not horizontal, vertical or diagonal"
+        );
+        assert_eq!(overlaps(&lines, false, Mode::Sweep)?, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_sweep_against_grid() {
+        let mut seed = 0x2021_0005u64;
+        let mut random = |n: u64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) % n) as i64
+        };
+
+        for _ in 0..50 {
+            let lines = (0..random(30) + 1)
+                .map(|_| {
+                    let (x, y, length) = (random(40) - 20, random(40) - 20, random(15));
+                    let (dx, dy) = [(1, 0), (0, 1), (1, 1), (1, -1)][random(4) as usize];
+                    let sign = if random(2) == 0 { 1 } else { -1 };
+                    Line::coords(x, y, x + sign * dx * length, y + sign * dy * length)
+                })
+                .collect::<Vec<Line>>();
+
+            let mut grid = Grid::default();
+            lines.iter().for_each(|l| grid.plot(l, true));
+            let segments = lines.iter().map(|l| l.0).collect::<Vec<Segment>>();
+            for threshold in 1..=4 {
+                assert_eq!(
+                    sweep::count(&segments, threshold),
+                    Ok(grid.points(threshold).count()),
+                    "{:?} at {}",
+                    lines,
+                    threshold
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn check_sweep_many_segments() -> Result<()> {
+        let mut seed = 0x2021_0021u64;
+        let mut random = |n: u64| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) % n) as i64
+        };
+
+        // Rasterising these would visit around a billion points; the sweep only
+        // visits segment ends and crossings.
+        let lines = (0..2000)
+            .map(|_| {
+                let (x, y, length) = (random(10_000_000), random(10_000_000), random(1_000_000));
+                let (dx, dy) = [(1, 0), (0, 1), (1, 1), (1, -1)][random(4) as usize];
+                Line::coords(x, y, x + dx * length, y + dy * length)
+            })
+            .collect::<Vec<Line>>();
+
+        let count = overlaps(&lines, true, Mode::Sweep)?;
+        assert!(count > 1000);
+        assert_eq!(count, overlaps(&lines, true, Mode::Exact)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_dense_grid() -> Result<()> {
+        let lines: Vec<Line> = try_inputs(fixture!("test.txt")?)?;
+        let mut dense = Grid::for_lines(&lines);
+        let mut sparse = Grid::default();
+        assert!(matches!(dense.counts, Counts::Dense { .. }));
+
+        lines.iter().for_each(|l| dense.plot(l, true));
+        lines.iter().for_each(|l| sparse.plot(l, true));
+        let mut dense_counts = dense.counts().collect::<Vec<(Point2, u32)>>();
+        let mut sparse_counts = sparse.counts().collect::<Vec<(Point2, u32)>>();
+        dense_counts.sort();
+        sparse_counts.sort();
+        assert_eq!(dense_counts, sparse_counts);
+        assert_eq!(dense.points(2).count(), 12);
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_dense_overflow() {
+        let line = Line::coords(0, 0, 1, 0);
+        let mut dense = Grid::for_lines(&[line]);
+        let mut sparse = Grid::default();
+        for _ in 0..u16::MAX {
+            dense.plot(&line, false);
+            sparse.plot(&line, false);
+        }
+        assert!(matches!(dense.counts, Counts::Dense { .. }));
+
+        dense.plot(&line, false);
+        sparse.plot(&line, false);
+        assert!(matches!(dense.counts, Counts::Sparse(_)));
+        assert_eq!(dense.points(65536).count(), 2);
+        assert_eq!(
+            dense.counts().collect::<HashMap<Point2, u32>>(),
+            sparse.counts().collect::<HashMap<Point2, u32>>()
+        );
+    }
+
+    #[test]
+    fn check_huge_bounds() -> Result<()> {
+        let lines = [
+            "0,0 -> 4294967295,4294967295".parse::<Line>()?,
+            "4294967290,4294967295 -> 4294967295,4294967295".parse::<Line>()?,
+            "4294967293,4294967290 -> 4294967293,4294967295".parse::<Line>()?,
+        ];
+
+        let mut grid = Grid::for_lines(&lines);
+        assert!(matches!(grid.counts, Counts::Sparse(_)));
+        lines.iter().for_each(|l| grid.plot(l, false));
+        assert_eq!(
+            grid.points(2).collect::<Vec<Point2>>(),
+            vec![Point2::new(4_294_967_293, 4_294_967_295)]
+        );
+        assert_eq!(overlaps(&lines, false, Mode::Raster)?, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_sparse_fallback() {
+        let mut grid = Grid::for_lines(&[
+            Line::coords(0, 0, 10_000_000, 0),
+            Line::coords(5_000_000, 0, 5_000_000, 10_000_000),
+        ]);
+        assert!(matches!(grid.counts, Counts::Sparse(_)));
+        grid.plot(&Line::coords(0, 0, 3, 0), false);
+        grid.plot(&Line::coords(2, 0, 2, 2), false);
+        assert_eq!(
+            grid.points(2).collect::<Vec<Point2>>(),
+            vec![Point2::new(2, 0)]
+        );
+
+        let line = Line::coords(0, 0, 5, 0);
+        let mut grid = Grid::for_lines(&[line]);
+        grid.plot(&line, false);
+        assert!(matches!(grid.counts, Counts::Dense { .. }));
+        grid.plot(&Line::coords(3, -1, 3, 1), false);
+        assert!(matches!(grid.counts, Counts::Sparse(_)));
+        assert_eq!(
+            grid.points(2).collect::<Vec<Point2>>(),
+            vec![Point2::new(3, 0)]
+        );
+        assert_eq!(grid.points(1).count(), 8);
+    }
+}
@@ -0,0 +1,5 @@
+use {anyhow::Result, common::solution::run, y2021_day5::Day5};
+
+fn main() -> Result<()> {
+    run::<Day5>()
+}
@@ -0,0 +1,353 @@
+use {
+    common::geom::{Point2, Segment},
+    std::collections::{BTreeMap, HashMap, HashSet},
+};
+
+// Every horizontal, vertical or diagonal segment lies on a line of one of
+// four families, identified by a key that is constant along the line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Family {
+    Horizontal,
+    Vertical,
+    Diagonal,
+    AntiDiagonal,
+}
+
+impl Family {
+    fn of(segment: &Segment) -> Option<Family> {
+        let delta = segment.delta();
+        if delta.y == 0 {
+            Some(Family::Horizontal)
+        } else if delta.x == 0 {
+            Some(Family::Vertical)
+        } else if delta.x == delta.y {
+            Some(Family::Diagonal)
+        } else if delta.x == -delta.y {
+            Some(Family::AntiDiagonal)
+        } else {
+            None
+        }
+    }
+
+    fn coefficients(&self) -> (i64, i64) {
+        match self {
+            Family::Horizontal => (0, 1),
+            Family::Vertical => (1, 0),
+            Family::Diagonal => (1, -1),
+            Family::AntiDiagonal => (1, 1),
+        }
+    }
+
+    fn key(&self, p: Point2) -> i64 {
+        let (a, b) = self.coefficients();
+        a * p.x + b * p.y
+    }
+
+    fn y(&self, key: i64, x: i64) -> i64 {
+        match self {
+            Family::Horizontal => key,
+            Family::Diagonal => x - key,
+            Family::AntiDiagonal => key - x,
+            Family::Vertical => unreachable!("a vertical line has no single y"),
+        }
+    }
+
+    fn meet(&self, key: i64, other: Family, other_key: i64) -> Option<Point2> {
+        let (a1, b1) = self.coefficients();
+        let (a2, b2) = other.coefficients();
+        let det = a1 * b2 - a2 * b1;
+        let x = key * b2 - other_key * b1;
+        let y = a1 * other_key - a2 * key;
+        (x % det == 0 && y % det == 0).then(|| Point2::new(x / det, y / det))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Run {
+    start: i64,
+    end: i64,
+    count: u32,
+}
+
+impl Run {
+    fn len(&self) -> usize {
+        (self.end - self.start + 1) as usize
+    }
+}
+
+fn runs(intervals: Vec<(i64, i64, u32)>) -> Vec<Run> {
+    let mut events = intervals
+        .into_iter()
+        .flat_map(|(start, end, weight)| [(start, weight as i64), (end + 1, -(weight as i64))])
+        .collect::<Vec<(i64, i64)>>();
+    events.sort_unstable();
+
+    let mut runs = Vec::new();
+    let mut count = 0;
+    let mut i = 0;
+    while i < events.len() {
+        let at = events[i].0;
+        while i < events.len() && events[i].0 == at {
+            count += events[i].1;
+            i += 1;
+        }
+        if count > 0 {
+            if let Some(&(next, _)) = events.get(i) {
+                runs.push(Run {
+                    start: at,
+                    end: next - 1,
+                    count: count as u32,
+                });
+            }
+        }
+    }
+    runs
+}
+
+type Line = (Family, i64);
+
+#[derive(Debug, Default)]
+struct Column {
+    starts: Vec<(Line, i64)>,
+    ends: Vec<Line>,
+    verticals: Vec<(i64, i64, u32)>,
+    crossings: Vec<(Line, Line)>,
+}
+
+impl Column {
+    fn is_crossing_only(&self) -> bool {
+        self.starts.is_empty() && self.ends.is_empty() && self.verticals.is_empty()
+    }
+}
+
+// The non-vertical lines crossing the current column, with how many of their
+// segments cover it, and how many of them are covered at least `threshold`
+// times.
+#[derive(Debug)]
+struct Active {
+    threshold: u32,
+    lines: HashMap<Line, u32>,
+    covered: usize,
+}
+
+impl Active {
+    fn new(threshold: u32) -> Self {
+        Self {
+            threshold,
+            lines: HashMap::new(),
+            covered: 0,
+        }
+    }
+
+    fn add(&mut self, line: Line) {
+        let count = self.lines.entry(line).or_default();
+        *count += 1;
+        if *count == self.threshold {
+            self.covered += 1;
+        }
+    }
+
+    fn remove(&mut self, line: Line) {
+        if let Some(count) = self.lines.get_mut(&line) {
+            if *count == self.threshold {
+                self.covered -= 1;
+            }
+            *count -= 1;
+            if *count == 0 {
+                self.lines.remove(&line);
+            }
+        }
+    }
+
+    // Merges the active lines and the vertical segments of column `x`.
+    fn column(&self, x: i64, verticals: &[(i64, i64, u32)]) -> usize {
+        let intervals = self
+            .lines
+            .iter()
+            .map(|(&(family, key), &count)| {
+                let y = family.y(key, x);
+                (y, y, count)
+            })
+            .chain(verticals.iter().copied())
+            .collect();
+
+        runs(intervals)
+            .iter()
+            .filter(|r| r.count >= self.threshold)
+            .map(Run::len)
+            .sum()
+    }
+
+    // Away from segment ends every active line covers its own point of the
+    // column, except where lines cross.
+    fn crossings(&self, x: i64, pairs: &[(Line, Line)]) -> usize {
+        let mut meets: HashMap<i64, HashSet<Line>> = HashMap::new();
+        for &(a, b) in pairs {
+            if self.lines.contains_key(&a) && self.lines.contains_key(&b) {
+                meets.entry(a.0.y(a.1, x)).or_default().extend([a, b]);
+            }
+        }
+
+        meets.values().fold(self.covered, |total, lines| {
+            let counts = lines.iter().map(|line| self.lines[line]);
+            let covered = counts.clone().filter(|&c| c >= self.threshold).count();
+            total - covered + usize::from(counts.sum::<u32>() >= self.threshold)
+        })
+    }
+}
+
+// Counts the points covered by at least `threshold` segments by sweeping a
+// vertical line across the plane. Only the columns where a segment starts or
+// ends, a vertical segment lies or two lines cross are visited; every other
+// column holds exactly one point per active line, so whole gaps between them
+// are counted at once.
+// Fails with the first segment that is not horizontal, vertical or diagonal.
+pub fn count(segments: &[Segment], threshold: u32) -> Result<usize, Segment> {
+    let threshold = threshold.max(1);
+    let mut columns: BTreeMap<i64, Column> = BTreeMap::new();
+    for segment in segments {
+        let family = Family::of(segment).ok_or(*segment)?;
+        let bounds = segment.bounds();
+        let (left, right) = (bounds.min.x, bounds.max.x);
+        if family == Family::Vertical {
+            let (top, bottom) = (bounds.min.y, bounds.max.y);
+            columns
+                .entry(left)
+                .or_default()
+                .verticals
+                .push((top, bottom, 1));
+        } else {
+            let line = (family, family.key(segment.start));
+            columns.entry(left).or_default().starts.push((line, right));
+            columns.entry(right + 1).or_default().ends.push(line);
+        }
+    }
+
+    let mut active = Active::new(threshold);
+    let mut total = 0;
+    let mut previous = None;
+    while let Some((x, column)) = columns.pop_first() {
+        if let Some(previous) = previous {
+            total += active.covered * (x - previous - 1) as usize;
+        }
+        previous = Some(x);
+
+        column.ends.iter().for_each(|&line| active.remove(line));
+        column.starts.iter().for_each(|&(line, _)| active.add(line));
+        total += if column.is_crossing_only() {
+            active.crossings(x, &column.crossings)
+        } else {
+            active.column(x, &column.verticals)
+        };
+
+        for &((family, key), end) in &column.starts {
+            for &(other, other_key) in active.lines.keys() {
+                if other == family {
+                    continue;
+                }
+                if let Some(p) = family.meet(key, other, other_key) {
+                    if p.x > x && p.x <= end {
+                        columns
+                            .entry(p.x)
+                            .or_default()
+                            .crossings
+                            .push(((family, key), (other, other_key)));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(x1: i64, y1: i64, x2: i64, y2: i64) -> Segment {
+        Segment::new(Point2::new(x1, y1), Point2::new(x2, y2))
+    }
+
+    #[test]
+    fn check_runs() {
+        assert_eq!(
+            runs(vec![(0, 5, 1), (3, 8, 1), (4, 4, 1), (10, 10, 1)]),
+            vec![
+                Run {
+                    start: 0,
+                    end: 2,
+                    count: 1
+                },
+                Run {
+                    start: 3,
+                    end: 3,
+                    count: 2
+                },
+                Run {
+                    start: 4,
+                    end: 4,
+                    count: 3
+                },
+                Run {
+                    start: 5,
+                    end: 5,
+                    count: 2
+                },
+                Run {
+                    start: 6,
+                    end: 8,
+                    count: 1
+                },
+                Run {
+                    start: 10,
+                    end: 10,
+                    count: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn check_meet() {
+        assert_eq!(
+            Family::Diagonal.meet(0, Family::AntiDiagonal, 8),
+            Some(Point2::new(4, 4))
+        );
+        assert_eq!(Family::Diagonal.meet(0, Family::AntiDiagonal, 7), None);
+        assert_eq!(
+            Family::Horizontal.meet(3, Family::Vertical, -2),
+            Some(Point2::new(-2, 3))
+        );
+    }
+
+    #[test]
+    fn check_count() {
+        let segments = [
+            segment(0, 0, 8, 8),
+            segment(8, 0, 0, 8),
+            segment(4, 0, 4, 8),
+            segment(0, 4, 8, 4),
+            segment(2, 4, 6, 4),
+        ];
+
+        assert_eq!(count(&segments, 4), Ok(1));
+        assert_eq!(count(&segments, 5), Ok(1));
+        assert_eq!(count(&segments, 6), Ok(0));
+        assert_eq!(count(&segments, 2), Ok(5));
+        assert_eq!(count(&[segment(0, 0, 2, 5)], 1), Err(segment(0, 0, 2, 5)));
+    }
+
+    #[test]
+    fn check_large_coordinates() {
+        let segments = [
+            segment(0, 5_000_000, 4_000_000, 5_000_000),
+            segment(9_000_000, 5_000_000, 1_000_000, 5_000_000),
+            segment(2_000_000, 0, 2_000_000, 9_000_000),
+            segment(0, 0, 3_000_000, 3_000_000),
+        ];
+
+        assert_eq!(count(&segments, 2), Ok(3_000_001 + 1));
+        assert_eq!(count(&segments, 3), Ok(1));
+    }
+}
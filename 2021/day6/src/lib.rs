@@ -0,0 +1,642 @@
+use {
+    anyhow::{anyhow, Error, Result},
+    common::{input::try_list, solution::Solution},
+    num_bigint::BigUint,
+    std::{io::BufRead, str::FromStr},
+};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 6;
+
+    type Input = Vec<Fish>;
+    type Part1 = usize;
+    type Part2 = u128;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Ok(try_list(reader)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        let mut school = School::new(input.to_vec());
+        school.next_days(80);
+
+        Ok(school.count())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        FastSchool::new(input).count_after(256)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lifecycle {
+    pub reset: u32,
+    pub newborn: u32,
+}
+
+impl Default for Lifecycle {
+    fn default() -> Self {
+        Self {
+            reset: 6,
+            newborn: 8,
+        }
+    }
+}
+
+impl Lifecycle {
+    fn timers(&self, fishes: &[Fish]) -> usize {
+        fishes
+            .iter()
+            .map(|f| f.count)
+            .chain([self.reset, self.newborn])
+            .max()
+            .unwrap_or(0) as usize
+            + 1
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fish {
+    count: u32,
+}
+
+impl Fish {
+    pub fn new(count: u32) -> Self {
+        Self { count }
+    }
+
+    fn spawn(&self, lifecycle: &Lifecycle) -> Fish {
+        Fish::new(lifecycle.newborn)
+    }
+
+    fn next_day(&mut self, lifecycle: &Lifecycle) -> Option<Fish> {
+        if self.count == 0 {
+            self.count = lifecycle.reset;
+            Some(self.spawn(lifecycle))
+        } else {
+            self.count -= 1;
+            None
+        }
+    }
+}
+
+impl FromStr for Fish {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let count = s.parse()?;
+
+        Ok(Fish::new(count))
+    }
+}
+
+#[derive(Debug)]
+pub struct School {
+    fishes: Vec<Fish>,
+    lifecycle: Lifecycle,
+}
+
+impl School {
+    pub fn new(fishes: Vec<Fish>) -> Self {
+        Self::with_lifecycle(fishes, Lifecycle::default())
+    }
+
+    pub fn with_lifecycle(fishes: Vec<Fish>, lifecycle: Lifecycle) -> Self {
+        Self { fishes, lifecycle }
+    }
+
+    pub fn next_day(&mut self) {
+        let mut spawn = vec![];
+        self.fishes.iter_mut().for_each(|fish| {
+            if let Some(fish) = fish.next_day(&self.lifecycle) {
+                spawn.push(fish);
+            }
+        });
+        self.fishes.append(&mut spawn);
+    }
+
+    pub fn next_days(&mut self, days: u32) {
+        for _ in 0..days {
+            self.next_day();
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.fishes.len()
+    }
+}
+
+#[derive(Debug)]
+pub struct FastSchool {
+    counters: Vec<usize>,
+    lifecycle: Lifecycle,
+}
+
+impl FastSchool {
+    pub fn new(fishes: &[Fish]) -> Self {
+        Self::with_lifecycle(fishes, Lifecycle::default())
+    }
+
+    pub fn with_lifecycle(fishes: &[Fish], lifecycle: Lifecycle) -> Self {
+        let mut counters = vec![0; lifecycle.timers(fishes)];
+
+        for fish in fishes {
+            counters[fish.count as usize] += 1;
+        }
+
+        Self {
+            counters,
+            lifecycle,
+        }
+    }
+
+    pub fn next_day(&mut self) {
+        let zero = self.counters[0];
+        self.counters.rotate_left(1);
+        *self.counters.last_mut().unwrap() = 0;
+
+        self.counters[self.lifecycle.reset as usize] += zero;
+        self.counters[self.lifecycle.newborn as usize] += zero;
+    }
+
+    pub fn next_days(&mut self, days: u32) {
+        for _ in 0..days {
+            self.next_day();
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.counters.iter().sum()
+    }
+}
+
+pub trait Count: Clone + Sized {
+    fn from_u64(n: u64) -> Self;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+}
+
+impl Count for u128 {
+    fn from_u64(n: u64) -> Self {
+        n as u128
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        u128::checked_add(*self, *other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        u128::checked_mul(*self, *other)
+    }
+}
+
+impl Count for BigUint {
+    fn from_u64(n: u64) -> Self {
+        BigUint::from(n)
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(self + other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self * other)
+    }
+}
+
+// Counts modulo M, for day counts whose exact answer has billions of digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Modulo<const M: u64>(pub u64);
+
+impl<const M: u64> Count for Modulo<M> {
+    fn from_u64(n: u64) -> Self {
+        Modulo(n % M)
+    }
+
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Modulo(
+            ((self.0 as u128 + other.0 as u128) % M as u128) as u64,
+        ))
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(Modulo(
+            ((self.0 as u128 * other.0 as u128) % M as u128) as u64,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Matrix<T> {
+    size: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Count> Matrix<T> {
+    fn from_fn(size: usize, f: impl Fn(usize, usize) -> u64) -> Self {
+        Self {
+            size,
+            cells: (0..size * size)
+                .map(|i| T::from_u64(f(i / size, i % size)))
+                .collect(),
+        }
+    }
+
+    fn identity(size: usize) -> Self {
+        Self::from_fn(size, |r, c| u64::from(r == c))
+    }
+
+    // One day: every timer moves down a slot, and the fish at zero both
+    // reset and spawn the same number of newborns.
+    fn day(size: usize, lifecycle: &Lifecycle) -> Self {
+        let (reset, newborn) = (lifecycle.reset as usize, lifecycle.newborn as usize);
+        Self::from_fn(size, |r, c| {
+            u64::from(c == r + 1)
+                + u64::from(c == 0 && r == reset)
+                + u64::from(c == 0 && r == newborn)
+        })
+    }
+
+    fn get(&self, row: usize, column: usize) -> &T {
+        &self.cells[row * self.size + column]
+    }
+
+    fn dot(row: impl Iterator<Item = T>, column: impl Iterator<Item = T>) -> Option<T> {
+        row.zip(column).try_fold(T::from_u64(0), |sum, (a, b)| {
+            sum.checked_add(&a.checked_mul(&b)?)
+        })
+    }
+
+    fn mul(&self, other: &Self) -> Option<Self> {
+        let size = self.size;
+        let cells = (0..size * size)
+            .map(|i| {
+                let (r, c) = (i / size, i % size);
+                Self::dot(
+                    (0..size).map(|k| self.get(r, k).clone()),
+                    (0..size).map(|k| other.get(k, c).clone()),
+                )
+            })
+            .collect::<Option<Vec<T>>>()?;
+        Some(Self { size, cells })
+    }
+
+    fn apply(&self, counters: &[T]) -> Option<Vec<T>> {
+        (0..self.size)
+            .map(|r| {
+                Self::dot(
+                    (0..self.size).map(|k| self.get(r, k).clone()),
+                    counters.iter().cloned(),
+                )
+            })
+            .collect()
+    }
+
+    fn pow(&self, mut exponent: u64) -> Option<Self> {
+        let mut result = Self::identity(self.size);
+        let mut base = self.clone();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul(&base)?;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.mul(&base)?;
+            }
+        }
+        Some(result)
+    }
+}
+
+fn advance<T: Count>(counters: &[T], lifecycle: &Lifecycle, days: u64) -> Option<Vec<T>> {
+    Matrix::day(counters.len(), lifecycle)
+        .pow(days)?
+        .apply(counters)
+}
+
+impl FastSchool {
+    pub fn count_after<T: Count>(&self, days: u64) -> Result<T> {
+        let counters = self
+            .counters
+            .iter()
+            .map(|&c| T::from_u64(c as u64))
+            .collect::<Vec<T>>();
+
+        advance(&counters, &self.lifecycle, days)
+            .and_then(|counters| {
+                counters
+                    .iter()
+                    .try_fold(T::from_u64(0), |sum, c| sum.checked_add(c))
+            })
+            .ok_or_else(|| anyhow!("Fish count overflows after {} days", days))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        proptest::{collection::vec, prelude::*},
+    };
+
+    #[test]
+    fn check_from_str() -> Result<()> {
+        assert_eq!("1".parse::<Fish>()?, Fish::new(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_fish_next_day_no_spawn() {
+        let mut fish = Fish::new(6);
+        let spawn = fish.next_day(&Lifecycle::default());
+        assert_eq!(fish.count, 5);
+        assert_eq!(spawn, None);
+    }
+
+    #[test]
+    fn check_fish_next_day_with_spawn() {
+        let mut fish = Fish::new(0);
+        let spawn = fish.next_day(&Lifecycle::default());
+        assert_eq!(fish.count, 6);
+        assert_eq!(spawn, Some(Fish::new(8)));
+    }
+
+    #[test]
+    fn check_school_next_day_no_spawn() {
+        let mut school = School::new(vec![
+            Fish::new(3),
+            Fish::new(4),
+            Fish::new(3),
+            Fish::new(1),
+            Fish::new(2),
+        ]);
+        school.next_day();
+        assert_eq!(school.count(), 5);
+        assert_eq!(
+            school.fishes,
+            vec![
+                Fish::new(2),
+                Fish::new(3),
+                Fish::new(2),
+                Fish::new(0),
+                Fish::new(1),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_school_next_day_with_spawn() {
+        let mut school = School::new(vec![
+            Fish::new(2),
+            Fish::new(3),
+            Fish::new(2),
+            Fish::new(0),
+            Fish::new(1),
+        ]);
+        school.next_day();
+        assert_eq!(school.count(), 6);
+        assert_eq!(
+            school.fishes,
+            vec![
+                Fish::new(1),
+                Fish::new(2),
+                Fish::new(1),
+                Fish::new(6),
+                Fish::new(0),
+                Fish::new(8),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_school_next_18_days() {
+        let mut school = School::new(vec![
+            Fish::new(3),
+            Fish::new(4),
+            Fish::new(3),
+            Fish::new(1),
+            Fish::new(2),
+        ]);
+        school.next_days(18);
+        assert_eq!(school.count(), 26);
+    }
+
+    #[test]
+    fn check_school_next_80_days() {
+        let mut school = School::new(vec![
+            Fish::new(3),
+            Fish::new(4),
+            Fish::new(3),
+            Fish::new(1),
+            Fish::new(2),
+        ]);
+        school.next_days(80);
+        assert_eq!(school.count(), 5934);
+    }
+
+    #[test]
+    fn check_fast_school_new() {
+        let school = FastSchool::new(&[
+            Fish::new(3),
+            Fish::new(4),
+            Fish::new(3),
+            Fish::new(1),
+            Fish::new(2),
+        ]);
+        assert_eq!(school.counters, vec![0, 1, 1, 2, 1, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn check_fast_school_next_day() {
+        let mut school = FastSchool::new(&[
+            Fish::new(3),
+            Fish::new(4),
+            Fish::new(3),
+            Fish::new(1),
+            Fish::new(2),
+        ]);
+        school.next_day();
+        assert_eq!(school.count(), 5);
+        assert_eq!(school.counters, vec![1, 1, 2, 1, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn check_fast_school_next_3_days() {
+        let mut school = FastSchool::new(&[
+            Fish::new(3),
+            Fish::new(4),
+            Fish::new(3),
+            Fish::new(1),
+            Fish::new(2),
+        ]);
+        school.next_days(3);
+        assert_eq!(school.count(), 7);
+        assert_eq!(school.counters, vec![2, 1, 0, 0, 0, 1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn check_fast_school_next_80_days() {
+        let mut school = FastSchool::new(&[
+            Fish::new(3),
+            Fish::new(4),
+            Fish::new(3),
+            Fish::new(1),
+            Fish::new(2),
+        ]);
+        school.next_days(80);
+        assert_eq!(school.count(), 5934);
+    }
+
+    #[test]
+    fn check_fast_school_next_256_days() {
+        let mut school = FastSchool::new(&[
+            Fish::new(3),
+            Fish::new(4),
+            Fish::new(3),
+            Fish::new(1),
+            Fish::new(2),
+        ]);
+        school.next_days(256);
+        assert_eq!(school.count(), 26984457539);
+    }
+
+    fn example() -> FastSchool {
+        FastSchool::new(&[
+            Fish::new(3),
+            Fish::new(4),
+            Fish::new(3),
+            Fish::new(1),
+            Fish::new(2),
+        ])
+    }
+
+    #[test]
+    fn check_matrix_day() {
+        let counters = example()
+            .counters
+            .iter()
+            .map(|&c| c as u128)
+            .collect::<Vec<u128>>();
+
+        assert_eq!(
+            advance(&counters, &Lifecycle::default(), 1),
+            Some(vec![1, 1, 2, 1, 0, 0, 0, 0, 0])
+        );
+        assert_eq!(
+            advance(&counters, &Lifecycle::default(), 3),
+            Some(vec![2, 1, 0, 0, 0, 1, 1, 1, 1])
+        );
+        assert_eq!(advance(&counters, &Lifecycle::default(), 0), Some(counters));
+    }
+
+    #[test]
+    fn check_count_after() -> Result<()> {
+        let school = example();
+
+        assert_eq!(school.count_after::<u128>(18)?, 26);
+        assert_eq!(school.count_after::<u128>(80)?, 5934);
+        assert_eq!(school.count_after::<u128>(256)?, 26984457539);
+        assert_eq!(
+            school.count_after::<BigUint>(256)?,
+            BigUint::from(26984457539u64)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_count_overflow() -> Result<()> {
+        let school = example();
+
+        assert_eq!(
+            school.count_after::<u128>(10_000).unwrap_err().to_string(),
+            "Fish count overflows after 10000 days"
+        );
+
+        let mut counters = school
+            .counters
+            .iter()
+            .map(|&c| BigUint::from(c))
+            .collect::<Vec<BigUint>>();
+        for _ in 0..10_000 {
+            counters.rotate_left(1);
+            counters[6] = &counters[6] + &counters[8];
+        }
+        let expected = counters.iter().sum::<BigUint>();
+
+        assert_eq!(school.count_after::<BigUint>(10_000)?, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_count_modulo() -> Result<()> {
+        type M = Modulo<1_000_000_007>;
+        let school = example();
+
+        assert_eq!(
+            school.count_after::<M>(256)?,
+            Modulo(26984457539 % 1_000_000_007)
+        );
+
+        let start = school
+            .counters
+            .iter()
+            .map(|&c| M::from_u64(c as u64))
+            .collect::<Vec<M>>();
+        let lifecycle = Lifecycle::default();
+        let half = advance(&start, &lifecycle, 500_000_000_000).unwrap();
+        assert_eq!(
+            advance(&half, &lifecycle, 500_000_000_000),
+            advance(&start, &lifecycle, 1_000_000_000_000)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_lifecycle() -> Result<()> {
+        let lifecycle = Lifecycle {
+            reset: 2,
+            newborn: 3,
+        };
+        let fishes = [Fish::new(5), Fish::new(0)];
+
+        let mut fast = FastSchool::with_lifecycle(&fishes, lifecycle);
+        assert_eq!(fast.counters, vec![1, 0, 0, 0, 0, 1]);
+        fast.next_day();
+        assert_eq!(fast.counters, vec![0, 0, 1, 1, 1, 0]);
+
+        let mut school = School::with_lifecycle(fishes.to_vec(), lifecycle);
+        school.next_days(20);
+        assert_eq!(school.count(), fast.count_after::<u128>(19)? as usize);
+
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn check_schools_agree(
+            reset in 2u32..8,
+            newborn in 2u32..10,
+            timers in vec(0u32..10, 0..10),
+            days in 0u32..40,
+        ) {
+            let lifecycle = Lifecycle { reset, newborn };
+            let fishes = timers.into_iter().map(Fish::new).collect::<Vec<Fish>>();
+
+            let mut school = School::with_lifecycle(fishes.clone(), lifecycle);
+            school.next_days(days);
+            let mut fast = FastSchool::with_lifecycle(&fishes, lifecycle);
+            let counted = fast.count_after::<u128>(days as u64).unwrap();
+            fast.next_days(days);
+
+            prop_assert_eq!(fast.count(), school.count());
+            prop_assert_eq!(counted, school.count() as u128);
+        }
+    }
+}
@@ -0,0 +1,5 @@
+use {anyhow::Result, common::solution::run, y2021_day6::Day6};
+
+fn main() -> Result<()> {
+    run::<Day6>()
+}
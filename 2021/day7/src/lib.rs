@@ -0,0 +1,279 @@
+use {
+    anyhow::{anyhow, Result},
+    common::{input::try_list, solution::Solution},
+    std::{cmp::Ordering, collections::HashMap, io::BufRead},
+};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 7;
+
+    type Input = Vec<u32>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Ok(try_list(reader)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        let cast = Cast::new(input);
+
+        cast.median()
+            .map(|(_, distance)| distance)
+            .ok_or_else(|| anyhow!("No crabs to align"))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        let cast = Cast::new(input);
+
+        cast.mean()
+            .map(|(_, distance)| distance)
+            .ok_or_else(|| anyhow!("No crabs to align"))
+    }
+}
+
+fn diff(one: u32, other: u32) -> u32 {
+    one.abs_diff(other)
+}
+
+fn cost(distance: u32) -> u64 {
+    let distance = u64::from(distance);
+    distance * (distance + 1) / 2
+}
+
+#[derive(Debug)]
+pub struct Cast {
+    distribution: HashMap<u32, u32>,
+    min: u32,
+    max: u32,
+}
+
+impl Cast {
+    pub fn new(crabs: &[u32]) -> Self {
+        let mut distribution = HashMap::new();
+        let mut min = u32::MAX;
+        let mut max = 0u32;
+
+        crabs.iter().for_each(|x| {
+            let x = *x;
+            *distribution.entry(x).or_default() += 1;
+            min = min.min(x);
+            max = max.max(x);
+        });
+
+        Self {
+            distribution,
+            min,
+            max,
+        }
+    }
+
+    fn crabs(&self) -> u64 {
+        self.distribution.values().map(|&v| u64::from(v)).sum()
+    }
+
+    pub fn distance(&self, from: u32, cost: impl Fn(u32) -> u64) -> u64 {
+        self.distribution
+            .iter()
+            .fold(0, |acc, (k, v)| acc + u64::from(*v) * cost(diff(from, *k)))
+    }
+
+    fn cheapest(
+        &self,
+        positions: impl Iterator<Item = u32>,
+        cost: impl Fn(u32) -> u64,
+    ) -> Option<(u32, u64)> {
+        positions
+            .map(|position| (position, self.distance(position, &cost)))
+            .min_by_key(|&(position, distance)| (distance, position))
+    }
+
+    pub fn min_distance(&self, cost: impl Fn(u32) -> u64) -> Option<(u32, u64)> {
+        self.cheapest(self.min..=self.max, cost)
+    }
+
+    // With a linear cost, any median minimises the total distance.
+    pub fn median(&self) -> Option<(u32, u64)> {
+        let mut positions = self.distribution.iter().collect::<Vec<_>>();
+        positions.sort_unstable();
+
+        let half = self.crabs().div_ceil(2);
+        let mut seen = 0;
+        let (&position, _) = positions.into_iter().find(|(_, &count)| {
+            seen += u64::from(count);
+            seen >= half
+        })?;
+
+        Some((position, self.distance(position, u64::from)))
+    }
+
+    // With a triangular cost, the optimum is within half a step of the mean,
+    // so only the positions around it need to be checked.
+    pub fn mean(&self) -> Option<(u32, u64)> {
+        let crabs = self.crabs();
+        if crabs == 0 {
+            return None;
+        }
+        let total = self
+            .distribution
+            .iter()
+            .map(|(&k, &v)| u64::from(k) * u64::from(v))
+            .sum::<u64>();
+        let mean = (total / crabs) as u32;
+
+        self.cheapest(
+            mean.saturating_sub(1).max(self.min)..=(mean + 1).min(self.max),
+            cost,
+        )
+    }
+
+    // Works for any cost that makes the total distance convex in the
+    // position, which holds whenever the cost itself is convex.
+    pub fn ternary(&self, cost: impl Fn(u32) -> u64) -> Option<(u32, u64)> {
+        let (mut low, mut high) = (self.min, self.max);
+
+        while high.saturating_sub(low) > 2 {
+            let third = (high - low) / 3;
+            let (left, right) = (low + third, high - third);
+            match self.distance(left, &cost).cmp(&self.distance(right, &cost)) {
+                Ordering::Less => high = right - 1,
+                Ordering::Greater => low = left + 1,
+                Ordering::Equal => (low, high) = (left, right),
+            }
+        }
+
+        self.cheapest(low..=high, cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        proptest::{collection::vec, prelude::*},
+    };
+
+    #[test]
+    fn check_cast() {
+        let input: Vec<u32> = vec![16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+        let cast = Cast::new(&input);
+
+        assert_eq!(cast.min, 0);
+        assert_eq!(cast.max, 16);
+        assert_eq!(cast.distribution.len(), 7);
+        assert_eq!(cast.distribution.values().sum::<u32>(), 10u32);
+    }
+
+    #[test]
+    fn check_distance() {
+        let input: Vec<u32> = vec![16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+        let cast = Cast::new(&input);
+
+        assert_eq!(cast.distance(1, u64::from), 41);
+        assert_eq!(cast.distance(2, u64::from), 37);
+        assert_eq!(cast.distance(3, u64::from), 39);
+        assert_eq!(cast.distance(10, u64::from), 71);
+    }
+
+    #[test]
+    fn check_min_position() {
+        let input: Vec<u32> = vec![16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+        let cast = Cast::new(&input);
+
+        assert_eq!(cast.min_distance(u64::from), Some((2, 37)));
+    }
+
+    #[test]
+    fn check_cost() {
+        assert_eq!(cost(1), 1);
+        assert_eq!(cost(2), 3);
+        assert_eq!(cost(3), 6);
+        assert_eq!(cost(4), 10);
+        assert_eq!(cost(5), 15);
+        assert_eq!(cost(10), 55);
+    }
+
+    #[test]
+    fn check_min_cost() {
+        let input: Vec<u32> = vec![16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+        let cast = Cast::new(&input);
+
+        assert_eq!(cast.min_distance(cost), Some((5, 168)));
+    }
+
+    #[test]
+    fn check_median() {
+        let input: Vec<u32> = vec![16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+        let cast = Cast::new(&input);
+
+        assert_eq!(cast.median(), Some((2, 37)));
+    }
+
+    #[test]
+    fn check_mean() {
+        let input: Vec<u32> = vec![16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+        let cast = Cast::new(&input);
+
+        assert_eq!(cast.mean(), Some((5, 168)));
+        assert_eq!(Cast::new(&[3]).mean(), Some((3, 0)));
+    }
+
+    #[test]
+    fn check_ternary() {
+        let input: Vec<u32> = vec![16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+        let cast = Cast::new(&input);
+
+        assert_eq!(cast.ternary(u64::from).map(|(_, d)| d), Some(37));
+        assert_eq!(cast.ternary(cost), Some((5, 168)));
+        assert_eq!(
+            cast.ternary(|x| u64::from(x).pow(2)),
+            cast.min_distance(|x| u64::from(x).pow(2))
+        );
+    }
+
+    #[test]
+    fn check_no_crabs() {
+        let cast = Cast::new(&[]);
+
+        assert_eq!(cast.median(), None);
+        assert_eq!(cast.mean(), None);
+        assert_eq!(cast.ternary(cost), None);
+        assert_eq!(cast.min_distance(cost), None);
+        assert!(Day7::part1(&vec![]).is_err());
+    }
+
+    #[test]
+    fn check_far_positions() {
+        assert_eq!(cost(100_000), 5_000_050_000);
+
+        let cast = Cast::new(&[0, 100_000, 100_000]);
+        assert_eq!(cast.median(), Some((100_000, 100_000)));
+        assert_eq!(cast.mean(), cast.min_distance(cost));
+        assert_eq!(cast.ternary(cost), cast.min_distance(cost));
+    }
+
+    proptest! {
+        #[test]
+        fn check_against_brute_force(crabs in vec(0u32..200, 1..40)) {
+            let cast = Cast::new(&crabs);
+
+            let distance = |found: Option<(u32, u64)>| found.map(|(_, d)| d);
+            let convex = |x: u32| u64::from(x) * u64::from(x) + 3 * u64::from(x);
+
+            prop_assert_eq!(distance(cast.median()), distance(cast.min_distance(u64::from)));
+            prop_assert_eq!(cast.mean(), cast.min_distance(cost));
+            prop_assert_eq!(
+                distance(cast.ternary(u64::from)),
+                distance(cast.min_distance(u64::from))
+            );
+            prop_assert_eq!(cast.ternary(cost), cast.min_distance(cost));
+            prop_assert_eq!(
+                distance(cast.ternary(convex)),
+                distance(cast.min_distance(convex))
+            );
+        }
+    }
+}
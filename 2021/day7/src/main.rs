@@ -0,0 +1,5 @@
+use {anyhow::Result, common::solution::run, y2021_day7::Day7};
+
+fn main() -> Result<()> {
+    run::<Day7>()
+}
@@ -1,32 +1,39 @@
 use {
-    anyhow::Result,
-    common::input::{from_path, inputs},
-    std::collections::{HashMap, HashSet},
+    anyhow::{Error, Result},
+    common::{input::try_inputs, parse::parse, solution::Solution},
+    std::{
+        collections::{HashMap, HashSet},
+        io::BufRead,
+        str::FromStr,
+    },
 };
 
-fn main() -> Result<()> {
-    part1("day8/data/input.txt")?;
-    part2("day8/data/input.txt")?;
+pub struct Day8;
 
-    Ok(())
-}
+impl Solution for Day8 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 8;
 
-fn part1(path: &str) -> Result<()> {
-    let inputs = inputs(to_usize, from_path(path)?);
-    println!("Day 8 Part 1 => {:?}", inputs.iter().sum::<usize>());
+    type Input = Vec<Note>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    Ok(())
-}
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Ok(try_inputs(reader)?)
+    }
 
-fn part2(path: &str) -> Result<()> {
-    let inputs = inputs(to_usize2, from_path(path)?);
-    println!("Day 8 Part 2 => {:?}", inputs.iter().sum::<usize>());
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        Ok(input.iter().map(unique_digits).sum())
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        Ok(input.iter().map(output_value).sum())
+    }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Default)]
 enum Digit {
+    #[default]
     Unknown,
     Zero,
     One,
@@ -96,12 +103,6 @@ impl Digit {
     }
 }
 
-impl Default for Digit {
-    fn default() -> Self {
-        Digit::Unknown
-    }
-}
-
 fn sort<T: Into<String>>(s: T) -> String {
     let s = s.into();
     let mut v: Vec<char> = s.trim().chars().collect();
@@ -243,32 +244,58 @@ impl Default for Segment {
     }
 }
 
-fn to_usize(s: String) -> Option<usize> {
-    s.split('|').nth(1).map(|s| {
-        s.trim()
-            .split(' ')
-            .filter_map(|s| Digit::unique(s.trim().len() as u32))
-            .count()
-    })
+#[derive(Debug)]
+pub struct Note {
+    signals: Vec<String>,
+    outputs: Vec<String>,
+}
+
+impl FromStr for Note {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (signals, outputs): (String, String) = parse("{signals} | {outputs}", s)?;
+        Ok(Note {
+            signals: signals.split_whitespace().map(String::from).collect(),
+            outputs: outputs.split_whitespace().map(String::from).collect(),
+        })
+    }
+}
+
+fn unique_digits(note: &Note) -> usize {
+    note.outputs
+        .iter()
+        .filter_map(|s| Digit::unique(s.len() as u32))
+        .count()
 }
 
-fn to_usize2(s: String) -> Option<usize> {
-    let mut i = s.split('|');
-    let segment = Segment::solve(&i.next().unwrap().split(' ').collect::<Vec<&str>>());
-    i.next().map(|s| {
-        s.trim()
-            .split(' ')
-            .filter_map(|s| Digit::decode(&segment, s))
-            .fold(0usize, |mut acc, v| {
-                acc = (acc * 10) + v.value();
-                acc
-            })
-    })
+fn output_value(note: &Note) -> usize {
+    let signals = note
+        .signals
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<&str>>();
+    let segment = Segment::solve(&signals);
+    note.outputs
+        .iter()
+        .filter_map(|s| Digit::decode(&segment, s.as_str()))
+        .fold(0usize, |mut acc, v| {
+            acc = (acc * 10) + v.value();
+            acc
+        })
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use {
+        super::*,
+        common::{examples, fixture},
+    };
+
+    examples! {
+        Day8;
+        check_example: "test.txt" => { part1: 26, part2: 61229 },
+    }
 
     #[test]
     fn check_digit() {
@@ -281,17 +308,32 @@ mod tests {
     }
 
     #[test]
-    fn check_to_usize() {
+    fn check_unique_digits() -> Result<()> {
         let s =
             "edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc";
-        assert_eq!(to_usize(s.to_string()), Some(3));
+        assert_eq!(unique_digits(&s.parse()?), 3);
+
+        Ok(())
     }
 
     #[test]
-    fn check_to_usize_v2() {
+    fn check_output_value() -> Result<()> {
         let s =
             "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
-        assert_eq!(to_usize2(s.to_string()), Some(5353));
+        assert_eq!(output_value(&s.parse()?), 5353);
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_note_failure() {
+        assert_eq!(
+            "acedgfb cdfbe gcdfa"
+                .parse::<Note>()
+                .unwrap_err()
+                .to_string(),
+            "expected ' | ' at column 1"
+        );
     }
 
     #[test]
@@ -332,8 +374,8 @@ mod tests {
 
     #[test]
     fn check_inputs() -> Result<()> {
-        let inputs = inputs(to_usize2, from_path("test/test.txt")?);
-        assert_eq!(inputs.iter().sum::<usize>(), 61229);
+        let inputs: Vec<Note> = try_inputs(fixture!("test.txt")?)?;
+        assert_eq!(inputs.iter().map(output_value).sum::<usize>(), 61229);
 
         Ok(())
     }
@@ -0,0 +1,5 @@
+use {anyhow::Result, common::solution::run, y2021_day8::Day8};
+
+fn main() -> Result<()> {
+    run::<Day8>()
+}
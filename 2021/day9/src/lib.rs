@@ -0,0 +1,256 @@
+use {
+    anyhow::Result,
+    common::{
+        geom::Point2,
+        grid::{Grid, Position},
+        solution::Solution,
+    },
+    std::{collections::HashSet, io::BufRead},
+};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u32 = 2021;
+    const DAY: u32 = 9;
+
+    type Input = Grid<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+        Ok(Grid::digits(reader)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1> {
+        let grid = Heightmap::new(input);
+        let low_points = grid.low_points();
+
+        Ok(low_points.iter().map(|p| p.risk()).sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2> {
+        let grid = Heightmap::new(input);
+        let basins = grid.basins(3);
+
+        Ok(basins.iter().product())
+    }
+}
+
+struct Heightmap<'a> {
+    cells: &'a Grid<u32>,
+}
+
+impl<'a> Heightmap<'a> {
+    fn new(cells: &'a Grid<u32>) -> Self {
+        Self { cells }
+    }
+
+    fn cell(&self, point: Point2) -> Option<Location> {
+        let position = Position::try_from(point).ok()?;
+        self.cells
+            .get(position)
+            .map(|&height| Location::new(point, height))
+    }
+
+    fn neighbours(&self, point: Point2) -> impl Iterator<Item = Location> + '_ {
+        point.orthogonal().filter_map(|p| self.cell(p))
+    }
+
+    fn low_point(&self, point: Point2) -> Option<Location> {
+        let location = self.cell(point)?;
+        if self.neighbours(point).all(|n| location.height < n.height) {
+            Some(location)
+        } else {
+            None
+        }
+    }
+
+    fn low_points(&self) -> Vec<Location> {
+        self.cells
+            .positions()
+            .filter_map(|p| self.low_point(Point2::from(p)))
+            .collect()
+    }
+
+    fn basin(&self, point: Point2) -> u32 {
+        let mut basin = HashSet::new();
+
+        self._basin(point, &mut basin);
+
+        basin.len() as u32
+    }
+
+    fn _basin(&self, point: Point2, basin: &mut HashSet<Point2>) {
+        match self.cell(point) {
+            Some(location) if !location.is_boundary() && basin.insert(point) => {
+                for n in self.neighbours(point) {
+                    self._basin(n.point, basin);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn basins(&self, count: usize) -> Vec<u32> {
+        let mut v: Vec<u32> = self
+            .low_points()
+            .iter()
+            .map(|l| self.basin(l.point))
+            .collect();
+
+        v.sort_unstable();
+
+        v.iter().copied().rev().take(count).collect::<Vec<u32>>()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct Location {
+    point: Point2,
+    height: u32,
+}
+
+impl Location {
+    fn new(point: Point2, height: u32) -> Self {
+        Self { point, height }
+    }
+
+    fn risk(&self) -> u32 {
+        self.height + 1
+    }
+
+    fn is_boundary(&self) -> bool {
+        self.height >= 9
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        common::{examples, fixture},
+    };
+
+    examples! {
+        Day9;
+        check_example: "test.txt" => { part1: 15, part2: 1134 },
+    }
+
+    #[test]
+    fn check_inputs() -> Result<()> {
+        let inputs = Grid::digits(fixture!("test.txt")?)?;
+
+        let grid = Heightmap::new(&inputs);
+        assert_eq!(grid.cells.rows(), 5);
+        assert_eq!(grid.cells.columns(), 10);
+        assert_eq!(
+            grid.cells.row(0).copied().collect::<Vec<u32>>(),
+            vec![2, 1, 9, 9, 9, 4, 3, 2, 1, 0]
+        );
+
+        Ok(())
+    }
+
+    fn test_inputs() -> Grid<u32> {
+        Grid::try_from(vec![
+            vec![2, 1, 9, 9, 9, 4, 3, 2, 1, 0],
+            vec![3, 9, 8, 7, 8, 9, 4, 9, 2, 1],
+            vec![9, 8, 5, 6, 7, 8, 9, 8, 9, 2],
+            vec![8, 7, 6, 7, 8, 9, 6, 7, 8, 9],
+            vec![9, 8, 9, 9, 9, 6, 5, 6, 7, 8],
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn check_grid() {
+        let inputs = test_inputs();
+        let grid = Heightmap::new(&inputs);
+        assert_eq!(grid.cells.rows(), 5);
+        assert_eq!(grid.cells.columns(), 10);
+        assert_eq!(
+            grid.cells.row(0).copied().collect::<Vec<u32>>(),
+            vec![2, 1, 9, 9, 9, 4, 3, 2, 1, 0]
+        );
+    }
+
+    #[test]
+    fn check_cell() {
+        let inputs = test_inputs();
+        let grid = Heightmap::new(&inputs);
+
+        assert_eq!(
+            grid.cell(Point2::new(0, 0)),
+            Some(Location::new(Point2::new(0, 0), 2)),
+            "cell"
+        );
+        assert_eq!(grid.cell(Point2::new(-1, 0)), None, "outside");
+        assert_eq!(
+            grid.neighbours(Point2::new(0, 0))
+                .map(|n| n.point)
+                .collect::<Vec<Point2>>(),
+            vec![Point2::new(1, 0), Point2::new(0, 1)],
+            "neighbours"
+        );
+    }
+
+    #[test]
+    fn check_low_point() {
+        let inputs = test_inputs();
+        let grid = Heightmap::new(&inputs);
+
+        assert_eq!(grid.low_point(Point2::new(0, 0)), None, "0.0");
+        assert_eq!(
+            grid.low_point(Point2::new(1, 0)),
+            Some(Location::new(Point2::new(1, 0), 1)),
+            "1.0"
+        );
+        assert_eq!(
+            grid.low_point(Point2::new(2, 2)),
+            Some(Location::new(Point2::new(2, 2), 5)),
+            "2.2"
+        );
+        assert_eq!(grid.low_point(Point2::new(4, 2)), None, "4.2");
+        assert_eq!(grid.low_point(Point2::new(9, 4)), None, "9.4");
+    }
+
+    #[test]
+    fn check_low_points() {
+        let inputs = test_inputs();
+        let grid = Heightmap::new(&inputs);
+
+        let low_points = grid.low_points();
+        assert_eq!(
+            low_points,
+            vec![
+                Location::new(Point2::new(1, 0), 1),
+                Location::new(Point2::new(9, 0), 0),
+                Location::new(Point2::new(2, 2), 5),
+                Location::new(Point2::new(6, 4), 5)
+            ]
+        );
+        assert_eq!(low_points.iter().map(|p| p.risk()).sum::<u32>(), 15);
+    }
+
+    #[test]
+    fn check_basin() {
+        let inputs = test_inputs();
+        let grid = Heightmap::new(&inputs);
+
+        assert_eq!(grid.basin(Point2::new(1, 0)), 3, "1.0");
+        assert_eq!(grid.basin(Point2::new(9, 0)), 9, "9.0");
+        assert_eq!(grid.basin(Point2::new(2, 2)), 14, "2.2");
+        assert_eq!(grid.basin(Point2::new(6, 4)), 9, "6.4");
+    }
+
+    #[test]
+    fn check_basins() {
+        let inputs = test_inputs();
+        let grid = Heightmap::new(&inputs);
+        let v = grid.basins(3);
+
+        assert_eq!(v, vec![14, 9, 9]);
+        assert_eq!(v.iter().product::<u32>(), 1134);
+    }
+}
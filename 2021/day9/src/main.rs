@@ -0,0 +1,5 @@
+use {anyhow::Result, common::solution::run, y2021_day9::Day9};
+
+fn main() -> Result<()> {
+    run::<Day9>()
+}
@@ -0,0 +1,221 @@
+use {
+    anyhow::{Error, Result},
+    common::{
+        error::ParseError,
+        input::from_path,
+        solution::{Part, Puzzle, Report},
+    },
+    serde::Serialize,
+    std::{
+        fmt::{self, Display, Formatter},
+        io::{Cursor, Read},
+        time::Duration,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn of(samples: &[Duration]) -> Option<Self> {
+        let mut samples = samples.to_vec();
+        samples.sort();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (*samples.get(middle.checked_sub(1)?)? + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Some(Self {
+            min: *samples.first()?,
+            median,
+            max: *samples.last()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Measurement {
+    pub year: u32,
+    pub day: u32,
+    pub stage: String,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Measurement {
+    fn new(report: &Report, stage: &str, samples: &[Duration]) -> Option<Self> {
+        let stats = Stats::of(samples)?;
+        let nanos = |d: Duration| u64::try_from(d.as_nanos()).unwrap_or(u64::MAX);
+
+        Some(Self {
+            year: report.year,
+            day: report.day,
+            stage: stage.to_string(),
+            runs: samples.len(),
+            min_ns: nanos(stats.min),
+            median_ns: nanos(stats.median),
+            max_ns: nanos(stats.max),
+        })
+    }
+}
+
+impl Display for Measurement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} Day {} {:<6} min {:>12?} median {:>12?} max {:>12?} ({} runs)",
+            self.year,
+            self.day,
+            self.stage,
+            Duration::from_nanos(self.min_ns),
+            Duration::from_nanos(self.median_ns),
+            Duration::from_nanos(self.max_ns),
+            self.runs
+        )
+    }
+}
+
+pub fn measure(reports: &[Report]) -> Vec<Measurement> {
+    let Some(first) = reports.first() else {
+        return Vec::new();
+    };
+    let parse = reports.iter().map(|r| r.parse).collect::<Vec<Duration>>();
+
+    Measurement::new(first, "parse", &parse)
+        .into_iter()
+        .chain(
+            first
+                .answers
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, answer, _))| answer.is_ok())
+                .filter_map(|(i, (part, _, _))| {
+                    let samples = reports
+                        .iter()
+                        .filter_map(|r| r.answers.get(i).map(|(_, _, elapsed)| *elapsed))
+                        .collect::<Vec<Duration>>();
+                    Measurement::new(first, &format!("part{}", part.number()), &samples)
+                }),
+        )
+        .collect()
+}
+
+pub type Failure = (Part, Error);
+
+pub fn failures(report: Report) -> Vec<Failure> {
+    report
+        .answers
+        .into_iter()
+        .filter_map(|(part, answer, _)| answer.err().map(|e| (part, e)))
+        .collect()
+}
+
+pub fn bench(puzzle: &Puzzle, runs: usize) -> Result<(Vec<Measurement>, Vec<Failure>)> {
+    let path = puzzle.input_path();
+    let mut bytes = Vec::new();
+    from_path(&path)?.read_to_end(&mut bytes)?;
+
+    let mut reports = (0..runs)
+        .map(|_| {
+            puzzle
+                .time(Box::new(Cursor::new(bytes.clone())), &Part::all())
+                .map_err(|e| ParseError::locate(e, &path))
+        })
+        .collect::<Result<Vec<Report>>>()?;
+
+    let measurements = measure(&reports);
+    let failed = if reports.is_empty() {
+        Vec::new()
+    } else {
+        failures(reports.swap_remove(0))
+    };
+    Ok((measurements, failed))
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, anyhow::anyhow, common::solution::Answer};
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_millis(*v)).collect()
+    }
+
+    fn report(parse: u64, part1: u64) -> Report {
+        let answer = Answer {
+            year: 2021,
+            day: 6,
+            part: Part::One,
+            value: "5934".to_string(),
+        };
+        Report {
+            year: 2021,
+            day: 6,
+            parse: Duration::from_millis(parse),
+            answers: vec![
+                (Part::One, Ok(answer), Duration::from_millis(part1)),
+                (
+                    Part::Two,
+                    Err(anyhow!("unsolved")),
+                    Duration::from_millis(1),
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn check_stats() {
+        let stats = Stats::of(&millis(&[5, 1, 3])).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+
+        let stats = Stats::of(&millis(&[4, 1, 2, 8])).unwrap();
+        assert_eq!(stats.median, Duration::from_millis(3));
+
+        assert_eq!(Stats::of(&[]), None);
+    }
+
+    #[test]
+    fn check_measure() {
+        let measurements = measure(&[report(1, 10), report(3, 30), report(2, 20)]);
+
+        assert_eq!(
+            measurements
+                .iter()
+                .map(|m| (m.stage.as_str(), m.median_ns))
+                .collect::<Vec<(&str, u64)>>(),
+            vec![("parse", 2_000_000), ("part1", 20_000_000)]
+        );
+        assert!(measure(&[]).is_empty());
+    }
+
+    #[test]
+    fn check_failures() {
+        let failures = failures(report(1, 10));
+
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, Part::Two);
+        assert_eq!(failures[0].1.to_string(), "unsolved");
+    }
+
+    #[test]
+    fn check_json() -> Result<()> {
+        let measurements = measure(&[report(1, 10)]);
+
+        assert_eq!(
+            serde_json::to_string(&measurements[1])?,
+            r#"{"year":2021,"day":6,"stage":"part1","runs":1,"min_ns":10000000,"median_ns":10000000,"max_ns":10000000}"#
+        );
+
+        Ok(())
+    }
+}
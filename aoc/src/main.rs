@@ -0,0 +1,393 @@
+mod bench;
+mod puzzles;
+mod scaffold;
+
+use {
+    anyhow::{anyhow, Error, Result},
+    clap::{Parser, Subcommand},
+    common::{
+        answers::{Answers, Verdict},
+        fetch::Provider,
+        input::Source,
+        solution::{crate_path, input_path, Part, Puzzle},
+    },
+    std::{fs, path::Path, process::ExitCode},
+};
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Advent of Code runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve one or more days, e.g. `run 2021 9` or `run 9` for the latest year
+    Run {
+        /// Optional year followed by the days to run, every day of the year if only a year is given
+        #[arg(value_name = "[YEAR] DAYS", required_unless_present = "all")]
+        days: Vec<u32>,
+        /// Run every registered day of every year
+        #[arg(long, conflicts_with = "days")]
+        all: bool,
+        /// Only run the given part
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+        /// Report parse and part timings
+        #[arg(long)]
+        time: bool,
+        /// Read the input from a file or `-` for stdin instead of data/input.txt
+        #[arg(long, value_name = "PATH")]
+        input: Option<Source>,
+        /// Use the given text as the input
+        #[arg(long, conflicts_with = "input")]
+        text: Option<String>,
+    },
+    /// Repeatedly time parse and each part
+    Bench {
+        /// Optional year followed by the days to benchmark, every registered day if omitted
+        #[arg(value_name = "[YEAR] DAYS")]
+        days: Vec<u32>,
+        /// Number of runs per day
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// Print results as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Create a new day crate and add it to the workspace
+    New {
+        /// Optional year followed by the day to create
+        #[arg(value_name = "[YEAR] DAY", num_args = 1..=2, required = true)]
+        day: Vec<u32>,
+    },
+    /// Download puzzle inputs into each day's data/input.txt
+    Fetch {
+        /// Optional year followed by the days to fetch
+        #[arg(value_name = "[YEAR] DAYS", required = true)]
+        days: Vec<u32>,
+        /// Download again even if the input is cached
+        #[arg(long)]
+        refresh: bool,
+    },
+    /// Check answers against each day's data/answers.toml
+    Verify {
+        /// Optional year followed by the days to verify, every registered day if omitted
+        #[arg(value_name = "[YEAR] DAYS")]
+        days: Vec<u32>,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            days,
+            all,
+            part,
+            time,
+            input,
+            text,
+        } => run(days, all, part, time, input.or(text.map(Source::Text))),
+        Command::Bench { days, runs, json } => bench(days, runs, json),
+        Command::New { day } => new(day),
+        Command::Fetch { days, refresh } => fetch(days, refresh),
+        Command::Verify { days } => verify(days),
+    }
+}
+
+fn run(
+    days: Vec<u32>,
+    all: bool,
+    part: Option<u32>,
+    time: bool,
+    source: Option<Source>,
+) -> ExitCode {
+    let parts = match part {
+        Some(part) => vec![Part::try_from(part).expect("part is validated by clap")],
+        None => Part::all().to_vec(),
+    };
+    let puzzles = match select(&days, all) {
+        Ok(puzzles) if source.is_some() && puzzles.len() != 1 => {
+            eprintln!("An explicit input needs exactly one day");
+            return ExitCode::FAILURE;
+        }
+        Ok(puzzles) => puzzles,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut code = ExitCode::SUCCESS;
+    for puzzle in puzzles {
+        let source = source
+            .clone()
+            .unwrap_or_else(|| Source::Path(puzzle.input_path().into()));
+        match solve(&puzzle, &source, &parts, time) {
+            Ok(true) => (),
+            Ok(false) => code = ExitCode::FAILURE,
+            Err(e) => {
+                eprintln!("{} Day {} failed => {:#}", puzzle.year, puzzle.day, e);
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+    code
+}
+
+fn select(args: &[u32], all: bool) -> Result<Vec<Puzzle>> {
+    let (year, days) = puzzles::split(args)?;
+    if all && year.is_none() {
+        return Ok(puzzles::puzzles());
+    }
+
+    let year = year.unwrap_or_else(puzzles::latest_year);
+    if days.is_empty() {
+        let puzzles = puzzles::puzzles()
+            .into_iter()
+            .filter(|p| p.year == year)
+            .collect::<Vec<Puzzle>>();
+        if puzzles.is_empty() {
+            return Err(anyhow!("No solutions for {}", year));
+        }
+        Ok(puzzles)
+    } else {
+        days.iter()
+            .map(|day| {
+                puzzles::find(year, *day)
+                    .ok_or_else(|| anyhow!("No solution for {} day {}", year, day))
+            })
+            .collect()
+    }
+}
+
+fn part_failed(puzzle: &Puzzle, part: Part, error: &Error) {
+    eprintln!(
+        "{} Day {} Part {} failed => {:#}",
+        puzzle.year,
+        puzzle.day,
+        part.number(),
+        error
+    );
+}
+
+fn solve(puzzle: &Puzzle, source: &Source, parts: &[Part], time: bool) -> Result<bool> {
+    let report = puzzle.time_source(source, parts)?;
+    let mut solved = true;
+    for (part, answer, elapsed) in &report.answers {
+        match answer {
+            Ok(answer) if time => println!("{} ({:?})", answer, elapsed),
+            Ok(answer) => println!("{}", answer),
+            Err(e) => {
+                part_failed(puzzle, *part, e);
+                solved = false;
+            }
+        }
+    }
+    if time {
+        println!(
+            "{} Day {} parse {:?}, total {:?}",
+            report.year,
+            report.day,
+            report.parse,
+            report.total()
+        );
+    }
+
+    Ok(solved)
+}
+
+fn bench(days: Vec<u32>, runs: u32, json: bool) -> ExitCode {
+    let puzzles = match select(&days, days.is_empty()) {
+        Ok(puzzles) => puzzles,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut code = ExitCode::SUCCESS;
+    let mut measurements = Vec::new();
+    for puzzle in puzzles {
+        match bench::bench(&puzzle, runs as usize) {
+            Ok((results, failures)) => {
+                if json {
+                    measurements.extend(results);
+                } else {
+                    results.iter().for_each(|m| println!("{}", m));
+                }
+                for (part, e) in &failures {
+                    part_failed(&puzzle, *part, e);
+                    code = ExitCode::FAILURE;
+                }
+            }
+            Err(e) => {
+                eprintln!("{} Day {} failed => {:#}", puzzle.year, puzzle.day, e);
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if json {
+        match serde_json::to_string_pretty(&measurements) {
+            Ok(s) => println!("{}", s),
+            Err(e) => {
+                eprintln!("{}", e);
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+    code
+}
+
+fn new(args: Vec<u32>) -> ExitCode {
+    let result = puzzles::split(&args).and_then(|(year, days)| match days {
+        [day] => {
+            let year = year.unwrap_or_else(puzzles::latest_year);
+            scaffold::create(".", year, *day).map(|()| (year, *day))
+        }
+        _ => Err(anyhow!("Expected [YEAR] DAY")),
+    });
+    match result {
+        Ok((year, day)) => {
+            println!("Created {}", crate_path(year, day));
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{:#}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn fetch(args: Vec<u32>, refresh: bool) -> ExitCode {
+    let (year, days) = match puzzles::split(&args) {
+        Ok((year, days)) => (year.unwrap_or_else(puzzles::latest_year), days),
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let provider = Provider::from_env();
+
+    let mut code = ExitCode::SUCCESS;
+    for &day in days {
+        let path = input_path(year, day);
+        let result = if Path::new(&path).parent().is_some_and(Path::exists) {
+            if refresh {
+                provider.refresh(year, day)
+            } else {
+                provider.input(year, day)
+            }
+            .and_then(|input| Ok(fs::write(&path, input)?))
+        } else {
+            Err(anyhow!(
+                "No crate for {} day {}, run `aoc new {} {}` first",
+                year,
+                day,
+                year,
+                day
+            ))
+        };
+        match result {
+            Ok(()) => println!("{} Day {} => {}", year, day, path),
+            Err(e) => {
+                eprintln!("{} Day {} failed => {:#}", year, day, e);
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+    code
+}
+
+#[derive(Debug, Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    unknown: usize,
+    errors: usize,
+}
+
+fn verify(days: Vec<u32>) -> ExitCode {
+    let puzzles = match select(&days, days.is_empty()) {
+        Ok(puzzles) => puzzles,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut tally = Tally::default();
+    for puzzle in puzzles {
+        if let Err(e) = check(&puzzle, &mut tally) {
+            eprintln!("{} Day {} failed => {:#}", puzzle.year, puzzle.day, e);
+            tally.errors += 1;
+        }
+    }
+
+    println!(
+        "{} passed, {} failed, {} unknown, {} errors",
+        tally.passed, tally.failed, tally.unknown, tally.errors
+    );
+    if tally.failed > 0 || tally.errors > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn check(puzzle: &Puzzle, tally: &mut Tally) -> Result<()> {
+    let answers = Answers::from_path(puzzle.answers_path())?;
+    let mut inputs = answers.inputs().map(String::from).collect::<Vec<String>>();
+    if !inputs.iter().any(|input| input == "input") {
+        inputs.insert(0, "input".to_string());
+    }
+
+    for input in inputs {
+        let path = ["txt", "txt.gz", "txt.zst"]
+            .iter()
+            .map(|extension| puzzle.data_path(&format!("{}.{}", input, extension)))
+            .find(|path| Path::new(path).exists())
+            .unwrap_or_else(|| puzzle.data_path(&format!("{}.txt", input)));
+        let report = match puzzle.time_path(&path, &Part::all()) {
+            Ok(report) => report,
+            Err(e) => {
+                eprintln!(
+                    "{} Day {} [{}] failed => {:#}",
+                    puzzle.year, puzzle.day, input, e
+                );
+                tally.errors += 1;
+                continue;
+            }
+        };
+        for (part, answer, _) in report.answers {
+            let answer = match answer {
+                Ok(answer) => answer,
+                Err(e) => {
+                    eprintln!(
+                        "{} Day {} Part {} [{}] failed => {:#}",
+                        puzzle.year,
+                        puzzle.day,
+                        part.number(),
+                        input,
+                        e
+                    );
+                    tally.errors += 1;
+                    continue;
+                }
+            };
+            let verdict = answers.check(&input, &answer);
+            match verdict {
+                Verdict::Pass => tally.passed += 1,
+                Verdict::Fail(_) => tally.failed += 1,
+                Verdict::Unknown => tally.unknown += 1,
+            }
+            println!("{} [{}] {}", answer, input, verdict);
+        }
+    }
+
+    Ok(())
+}
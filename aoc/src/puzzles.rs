@@ -0,0 +1,80 @@
+use {
+    anyhow::{anyhow, Result},
+    common::solution::Puzzle,
+};
+
+pub const FIRST_YEAR: u32 = 2015;
+
+pub fn puzzles() -> Vec<Puzzle> {
+    vec![
+        Puzzle::of::<y2021_day1::Day1>(),
+        Puzzle::of::<y2021_day2::Day2>(),
+        Puzzle::of::<y2021_day3::Day3>(),
+        Puzzle::of::<y2021_day4::Day4>(),
+        Puzzle::of::<y2021_day5::Day5>(),
+        Puzzle::of::<y2021_day6::Day6>(),
+        Puzzle::of::<y2021_day7::Day7>(),
+        Puzzle::of::<y2021_day8::Day8>(),
+        Puzzle::of::<y2021_day9::Day9>(),
+        Puzzle::of::<y2021_day10::Day10>(),
+        Puzzle::of::<y2021_day11::Day11>(),
+        Puzzle::of::<y2021_day12::Day12>(),
+    ]
+}
+
+pub fn find(year: u32, day: u32) -> Option<Puzzle> {
+    puzzles()
+        .into_iter()
+        .find(|p| p.year == year && p.day == day)
+}
+
+pub fn latest_year() -> u32 {
+    puzzles().iter().map(|p| p.year).max().unwrap_or(FIRST_YEAR)
+}
+
+pub fn split(args: &[u32]) -> Result<(Option<u32>, &[u32])> {
+    let (year, days) = match args.split_first() {
+        Some((year, days)) if *year >= FIRST_YEAR => (Some(*year), days),
+        _ => (None, args),
+    };
+    if let Some(day) = days.iter().find(|day| !(1..=25).contains(*day)) {
+        return Err(anyhow!("Invalid day {}", day));
+    }
+
+    Ok((year, days))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_puzzles() {
+        let days = puzzles()
+            .iter()
+            .map(|p| (p.year, p.day))
+            .collect::<Vec<(u32, u32)>>();
+
+        assert_eq!(days, (1..=12).map(|day| (2021, day)).collect::<Vec<_>>());
+        assert_eq!(latest_year(), 2021);
+    }
+
+    #[test]
+    fn check_find() {
+        assert_eq!(find(2021, 9).map(|p| p.day), Some(9));
+        assert!(find(2021, 25).is_none());
+        assert!(find(2020, 9).is_none());
+    }
+
+    #[test]
+    fn check_split() -> Result<()> {
+        assert_eq!(split(&[2021, 9, 10])?, (Some(2021), &[9, 10][..]));
+        assert_eq!(split(&[2021])?, (Some(2021), &[][..]));
+        assert_eq!(split(&[9])?, (None, &[9][..]));
+        assert_eq!(split(&[])?, (None, &[][..]));
+        assert!(split(&[2021, 26]).is_err());
+        assert!(split(&[0]).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,301 @@
+use {
+    anyhow::{anyhow, Context, Result},
+    common::solution::crate_path,
+    std::{fs, path::Path},
+    toml_edit::{value, Array, DocumentMut, InlineTable, Value},
+};
+
+const CARGO: &str = include_str!("../template/Cargo.toml.tmpl");
+const LIB: &str = include_str!("../template/lib.rs.tmpl");
+const MAIN: &str = include_str!("../template/main.rs.tmpl");
+
+fn render(template: &str, year: u32, day: u32) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+}
+
+fn year_day(member: &str) -> Option<(u32, u32)> {
+    let (year, day) = member.split_once('/')?;
+    Some((year.parse().ok()?, day.strip_prefix("day")?.parse().ok()?))
+}
+
+fn array<'a>(manifest: &'a mut DocumentMut, key: &str) -> Result<&'a mut Array> {
+    manifest["workspace"][key]
+        .as_array_mut()
+        .ok_or_else(|| anyhow!("Missing workspace.{} in Cargo.toml", key))
+}
+
+pub fn register(manifest: &str, year: u32, day: u32) -> Result<String> {
+    let mut manifest = manifest.parse::<DocumentMut>()?;
+    let name = crate_path(year, day);
+    let after = |m: &Value| {
+        m.as_str()
+            .and_then(year_day)
+            .is_some_and(|k| k > (year, day))
+    };
+
+    let members = array(&mut manifest, "members")?;
+    if !members.iter().any(|m| m.as_str() == Some(&name)) {
+        let position = members.iter().position(after).unwrap_or(members.len());
+        members.insert(position, name.as_str());
+    }
+
+    let defaults = array(&mut manifest, "default-members")?;
+    defaults.retain(|m| m.as_str().and_then(year_day).is_none());
+    defaults.push(name.as_str());
+
+    Ok(manifest.to_string())
+}
+
+pub fn register_dependency(manifest: &str, year: u32, day: u32) -> Result<String> {
+    let mut manifest = manifest.parse::<DocumentMut>()?;
+    let dependencies = manifest["dependencies"]
+        .as_table_mut()
+        .ok_or_else(|| anyhow!("Missing dependencies in aoc/Cargo.toml"))?;
+
+    let name = format!("y{}-day{}", year, day);
+    if !dependencies.contains_key(&name) {
+        let mut dependency = InlineTable::new();
+        dependency.insert("path", format!("../{}", crate_path(year, day)).into());
+        dependencies.insert(&name, value(dependency));
+    }
+
+    Ok(manifest.to_string())
+}
+
+fn puzzle_day(line: &str) -> Option<(u32, u32)> {
+    let name = line.trim().strip_prefix("Puzzle::of::<y")?;
+    let (year, rest) = name.split_once("_day")?;
+    let (day, _) = rest.split_once("::")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+pub fn register_puzzle(source: &str, year: u32, day: u32) -> Result<String> {
+    let mut lines = source.lines().collect::<Vec<&str>>();
+    let missing = || anyhow!("Missing puzzles() list in aoc/src/puzzles.rs");
+    let function = lines
+        .iter()
+        .position(|l| l.trim_start().starts_with("pub fn puzzles()"))
+        .ok_or_else(missing)?;
+    let start = function
+        + 1
+        + lines[function..]
+            .iter()
+            .position(|l| l.trim_end().ends_with("vec!["))
+            .ok_or_else(missing)?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|l| puzzle_day(l).is_none())
+            .unwrap_or(lines.len() - start);
+
+    if lines[start..end]
+        .iter()
+        .any(|l| puzzle_day(l) == Some((year, day)))
+    {
+        return Ok(source.to_string());
+    }
+    let position = lines[start..end]
+        .iter()
+        .position(|l| puzzle_day(l).is_some_and(|k| k > (year, day)))
+        .map_or(end, |i| start + i);
+    let line = format!("        Puzzle::of::<y{0}_day{1}::Day{1}>(),", year, day);
+    lines.insert(position, &line);
+
+    let mut source = lines.join("\n");
+    source.push('\n');
+    Ok(source)
+}
+
+pub fn create<P: AsRef<Path>>(root: P, year: u32, day: u32) -> Result<()> {
+    let root = root.as_ref();
+    let dir = root.join(crate_path(year, day));
+    if dir.exists() {
+        return Err(anyhow!("{} already exists", dir.display()));
+    }
+
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)
+        .with_context(|| format!("Cannot read {}", manifest_path.display()))?;
+    let manifest = register(&manifest, year, day)?;
+
+    let aoc_path = root.join("aoc/Cargo.toml");
+    let aoc = fs::read_to_string(&aoc_path)
+        .with_context(|| format!("Cannot read {}", aoc_path.display()))?;
+    let aoc = register_dependency(&aoc, year, day)?;
+
+    let puzzles_path = root.join("aoc/src/puzzles.rs");
+    let puzzles = fs::read_to_string(&puzzles_path)
+        .with_context(|| format!("Cannot read {}", puzzles_path.display()))?;
+    let puzzles = register_puzzle(&puzzles, year, day)?;
+
+    for sub in ["src", "data", "test"] {
+        fs::create_dir_all(dir.join(sub))?;
+    }
+    fs::write(dir.join("Cargo.toml"), render(CARGO, year, day))?;
+    fs::write(dir.join("src/lib.rs"), render(LIB, year, day))?;
+    fs::write(dir.join("src/main.rs"), render(MAIN, year, day))?;
+    fs::write(dir.join("data/input.txt"), "")?;
+    fs::write(dir.join("test/test.txt"), "")?;
+    fs::write(manifest_path, manifest)?;
+    fs::write(aoc_path, aoc)?;
+    fs::write(puzzles_path, puzzles)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        common::solution::{solve, Part},
+        std::io::Cursor,
+    };
+
+    mod template {
+        include!(concat!(env!("OUT_DIR"), "/template.rs"));
+    }
+
+    const MANIFEST: &str = r#"[workspace]
+members = ["common", "aoc", "2021/day1", "2021/day2", "2021/day12"]
+default-members = ["common", "aoc", "2021/day12"]"#;
+
+    #[test]
+    fn check_register() -> Result<()> {
+        assert_eq!(
+            register(MANIFEST, 2021, 13)?,
+            r#"[workspace]
+members = ["common", "aoc", "2021/day1", "2021/day2", "2021/day12", "2021/day13"]
+default-members = ["common", "aoc", "2021/day13"]
+"#
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_register_between() -> Result<()> {
+        let manifest = register(MANIFEST, 2021, 3)?;
+
+        assert!(manifest.contains(r#""2021/day2", "2021/day3", "2021/day12"]"#));
+        assert_eq!(register(&manifest, 2021, 3)?, manifest);
+
+        let manifest = register(MANIFEST, 2020, 25)?;
+        assert!(manifest.contains(r#"["common", "aoc", "2020/day25", "2021/day1","#));
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_register_failure() {
+        assert!(register("[package]\nname = \"aoc\"", 2021, 13).is_err());
+    }
+
+    #[test]
+    fn check_register_dependency() -> Result<()> {
+        let manifest = r#"[package]
+name = "aoc"
+
+[dependencies]
+common = { path = "../common" }
+y2021-day12 = { path = "../2021/day12" }
+"#;
+        let registered = register_dependency(manifest, 2021, 13)?;
+
+        assert!(registered.ends_with(
+            r#"y2021-day12 = { path = "../2021/day12" }
+y2021-day13 = { path = "../2021/day13" }
+"#
+        ));
+        assert_eq!(register_dependency(&registered, 2021, 13)?, registered);
+        assert!(register_dependency("[package]\nname = \"aoc\"", 2021, 13).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_register_puzzle() -> Result<()> {
+        let source = r#"pub fn puzzles() -> Vec<Puzzle> {
+    vec![
+        Puzzle::of::<y2021_day2::Day2>(),
+        Puzzle::of::<y2021_day12::Day12>(),
+    ]
+}
+"#;
+
+        assert_eq!(
+            register_puzzle(source, 2021, 13)?,
+            r#"pub fn puzzles() -> Vec<Puzzle> {
+    vec![
+        Puzzle::of::<y2021_day2::Day2>(),
+        Puzzle::of::<y2021_day12::Day12>(),
+        Puzzle::of::<y2021_day13::Day13>(),
+    ]
+}
+"#
+        );
+        let registered = register_puzzle(source, 2021, 3)?;
+        assert!(registered.contains(
+            "Day2>(),\n        Puzzle::of::<y2021_day3::Day3>(),\n        Puzzle::of::<y2021_day12"
+        ));
+        assert_eq!(register_puzzle(&registered, 2021, 3)?, registered);
+        assert!(register_puzzle("fn main() {}\n", 2021, 13).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_create() -> Result<()> {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc/src"))?;
+        fs::write(root.join("Cargo.toml"), MANIFEST)?;
+        fs::write(root.join("aoc/Cargo.toml"), "[dependencies]\n")?;
+        fs::write(
+            root.join("aoc/src/puzzles.rs"),
+            "pub fn puzzles() -> Vec<Puzzle> {\n    vec![\n    ]\n}\n",
+        )?;
+
+        create(&root, 2021, 13)?;
+        let puzzles = fs::read_to_string(root.join("aoc/src/puzzles.rs"))?;
+        let aoc = fs::read_to_string(root.join("aoc/Cargo.toml"))?;
+        let exists = root.join("2021/day13/src/lib.rs").exists();
+        fs::remove_dir_all(&root)?;
+
+        assert!(exists);
+        assert!(puzzles.contains("        Puzzle::of::<y2021_day13::Day13>(),\n    ]"));
+        assert!(aoc.contains(r#"y2021-day13 = { path = "../2021/day13" }"#));
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_render() {
+        let lib = render(LIB, 2022, 13);
+
+        assert!(lib.contains("pub struct Day13;"));
+        assert!(lib.contains("const YEAR: u32 = 2022;"));
+        assert!(lib.contains("const DAY: u32 = 13;"));
+        assert!(!lib.contains("{day}"));
+        assert_eq!(
+            render(CARGO, 2022, 13).lines().nth(1),
+            Some(r#"name = "y2022-day13""#)
+        );
+        assert!(render(MAIN, 2022, 13).contains("y2022_day13::Day13"));
+    }
+
+    #[test]
+    fn check_template_solves() -> Result<()> {
+        let answers = solve::<template::Day1, _>(Cursor::new("a\nb\nc"), &Part::all())?;
+
+        assert_eq!(
+            answers
+                .iter()
+                .map(|a| a.to_string())
+                .collect::<Vec<String>>(),
+            vec!["2000 Day 1 Part 1 => 3", "2000 Day 1 Part 2 => 0"]
+        );
+
+        Ok(())
+    }
+}
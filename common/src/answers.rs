@@ -0,0 +1,202 @@
+use {
+    crate::solution::{Answer, Part},
+    anyhow::{anyhow, Context, Result},
+    std::{
+        collections::BTreeMap,
+        fmt::{self, Display, Formatter},
+        fs,
+        io::ErrorKind,
+        path::Path,
+        str::FromStr,
+    },
+    toml::{Table, Value},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail(expected) => write!(f, "FAIL (expected {})", expected),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    inputs: BTreeMap<String, BTreeMap<Part, String>>,
+}
+
+impl Answers {
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(s) => s
+                .parse()
+                .with_context(|| format!("Invalid answers in {}", path.display())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn inputs(&self) -> impl Iterator<Item = &str> {
+        self.inputs.keys().map(|s| s.as_str())
+    }
+
+    pub fn get(&self, input: &str, part: Part) -> Option<&str> {
+        self.inputs
+            .get(input)
+            .and_then(|parts| parts.get(&part))
+            .map(|s| s.as_str())
+    }
+
+    pub fn insert(&mut self, input: &str, part: Part, value: &str) {
+        self.inputs
+            .entry(input.to_string())
+            .or_default()
+            .insert(part, value.to_string());
+    }
+
+    pub fn check(&self, input: &str, answer: &Answer) -> Verdict {
+        match self.get(input, answer.part) {
+            Some(expected) if expected == answer.value => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.to_string()),
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+fn key(part: Part) -> String {
+    format!("part{}", part.number())
+}
+
+impl FromStr for Answers {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let table: Table = s.parse()?;
+        let mut answers = Self::default();
+
+        for (input, parts) in table {
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| anyhow!("Expected a table for input '{}'", input))?;
+            for part in Part::all() {
+                match parts.get(&key(part)) {
+                    Some(Value::String(s)) => answers.insert(&input, part, s),
+                    Some(Value::Integer(i)) => answers.insert(&input, part, &i.to_string()),
+                    Some(value) => {
+                        return Err(anyhow!(
+                            "Unsupported answer {} for {}.{}",
+                            value,
+                            input,
+                            key(part)
+                        ))
+                    }
+                    None => (),
+                }
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, (input, parts)) in self.inputs.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "[{}]", input)?;
+            for (part, value) in parts {
+                writeln!(f, "{} = {:?}", key(*part), value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(part: Part, value: &str) -> Answer {
+        Answer {
+            year: 2021,
+            day: 1,
+            part,
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn check_parse() -> Result<()> {
+        let answers: Answers =
+            "[input]\npart1 = 1548\npart2 = \"1589\"\n\n[example]\npart1 = 7\n".parse()?;
+
+        assert_eq!(
+            answers.inputs().collect::<Vec<&str>>(),
+            vec!["example", "input"]
+        );
+        assert_eq!(answers.get("input", Part::One), Some("1548"));
+        assert_eq!(answers.get("input", Part::Two), Some("1589"));
+        assert_eq!(answers.get("example", Part::Two), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_parse_failure() {
+        assert!("input = 1".parse::<Answers>().is_err());
+        assert!("[input]\npart1 = 1.5".parse::<Answers>().is_err());
+    }
+
+    #[test]
+    fn check_verdict() {
+        let mut answers = Answers::default();
+        answers.insert("input", Part::One, "1548");
+
+        assert_eq!(
+            answers.check("input", &answer(Part::One, "1548")),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.check("input", &answer(Part::One, "1549")),
+            Verdict::Fail("1548".to_string())
+        );
+        assert_eq!(
+            answers.check("input", &answer(Part::Two, "1")),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn check_display() -> Result<()> {
+        let mut answers = Answers::default();
+        answers.insert("input", Part::One, "1548");
+        answers.insert("input", Part::Two, "1589");
+
+        assert_eq!(
+            answers.to_string(),
+            "[input]\npart1 = \"1548\"\npart2 = \"1589\"\n"
+        );
+        assert_eq!(answers.to_string().parse::<Answers>()?, answers);
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_missing_file() -> Result<()> {
+        assert_eq!(Answers::from_path("test/missing.toml")?, Answers::default());
+
+        Ok(())
+    }
+}
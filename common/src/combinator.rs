@@ -0,0 +1,491 @@
+use {
+    crate::{convert::Integer, error::ParseError},
+    std::fmt::Display,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn column(&self, s: &str) -> usize {
+        column(s, self.start)
+    }
+
+    pub fn slice<'a>(&self, s: &'a str) -> &'a str {
+        &s[self.start..self.end]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub pos: usize,
+    pub expected: Vec<String>,
+    pub message: Option<String>,
+}
+
+impl Failure {
+    pub fn expected<T: Display>(pos: usize, expected: T) -> Self {
+        Self {
+            pos,
+            expected: vec![expected.to_string()],
+            message: None,
+        }
+    }
+
+    pub fn message<T: Display>(pos: usize, message: T) -> Self {
+        Self {
+            pos,
+            expected: Vec::new(),
+            message: Some(message.to_string()),
+        }
+    }
+
+    fn merge(self, other: Self) -> Self {
+        if self.pos != other.pos {
+            return if self.pos > other.pos { self } else { other };
+        }
+        if self.message.is_some() {
+            return self;
+        }
+        if other.message.is_some() {
+            return other;
+        }
+        let mut expected = self.expected;
+        for e in other.expected {
+            if !expected.contains(&e) {
+                expected.push(e);
+            }
+        }
+        Self { expected, ..self }
+    }
+
+    pub fn reason(&self) -> String {
+        if let Some(message) = &self.message {
+            return message.clone();
+        }
+        match &self.expected[..] {
+            [] => "unexpected input".to_string(),
+            [one] => format!("expected {}", one),
+            [init @ .., last] => format!("expected {} or {}", init.join(", "), last),
+        }
+    }
+
+    pub fn at(&self, line: usize, text: &str) -> ParseError {
+        ParseError::invalid(line, column(text, self.pos), text, self.reason())
+    }
+}
+
+pub type Parsed<T> = Result<(T, usize), Failure>;
+
+pub trait Parser<T> {
+    fn parse_at(&self, s: &str, pos: usize) -> Parsed<T>;
+
+    fn by_ref(&self) -> impl Parser<T> + '_ {
+        move |s: &str, pos: usize| self.parse_at(s, pos)
+    }
+
+    fn map<U>(self, f: impl Fn(T) -> U) -> impl Parser<U>
+    where
+        Self: Sized,
+    {
+        move |s: &str, pos: usize| self.parse_at(s, pos).map(|(value, next)| (f(value), next))
+    }
+
+    fn try_map<U, E: Display>(self, f: impl Fn(T) -> Result<U, E>) -> impl Parser<U>
+    where
+        Self: Sized,
+    {
+        move |s: &str, pos: usize| {
+            let (value, next) = self.parse_at(s, pos)?;
+            f(value)
+                .map(|value| (value, next))
+                .map_err(|e| Failure::message(pos, e))
+        }
+    }
+
+    fn then<U>(self, other: impl Parser<U>) -> impl Parser<(T, U)>
+    where
+        Self: Sized,
+    {
+        move |s: &str, pos: usize| {
+            let (a, pos) = self.parse_at(s, pos)?;
+            let (b, pos) = other.parse_at(s, pos)?;
+            Ok(((a, b), pos))
+        }
+    }
+
+    fn left<U>(self, other: impl Parser<U>) -> impl Parser<T>
+    where
+        Self: Sized,
+    {
+        self.then(other).map(|(a, _)| a)
+    }
+
+    fn right<U>(self, other: impl Parser<U>) -> impl Parser<U>
+    where
+        Self: Sized,
+    {
+        self.then(other).map(|(_, b)| b)
+    }
+
+    fn or(self, other: impl Parser<T>) -> impl Parser<T>
+    where
+        Self: Sized,
+    {
+        move |s: &str, pos: usize| {
+            self.parse_at(s, pos)
+                .or_else(|a| other.parse_at(s, pos).map_err(|b| a.merge(b)))
+        }
+    }
+
+    fn optional(self) -> impl Parser<Option<T>>
+    where
+        Self: Sized,
+    {
+        move |s: &str, pos: usize| match attempt(self.parse_at(s, pos), pos)? {
+            Some((value, next)) => Ok((Some(value), next)),
+            None => Ok((None, pos)),
+        }
+    }
+
+    fn many(self) -> impl Parser<Vec<T>>
+    where
+        Self: Sized,
+    {
+        move |s: &str, mut pos: usize| {
+            let mut values = Vec::new();
+            while let Some((value, next)) = attempt(self.parse_at(s, pos), pos)? {
+                if next == pos {
+                    break;
+                }
+                values.push(value);
+                pos = next;
+            }
+            Ok((values, pos))
+        }
+    }
+
+    fn many1(self) -> impl Parser<Vec<T>>
+    where
+        Self: Sized,
+    {
+        move |s: &str, pos: usize| {
+            let (first, mut pos) = self.parse_at(s, pos)?;
+            let mut values = vec![first];
+            while let Some((value, next)) = attempt(self.parse_at(s, pos), pos)? {
+                if next == pos {
+                    break;
+                }
+                values.push(value);
+                pos = next;
+            }
+            Ok((values, pos))
+        }
+    }
+
+    fn separated_by<U>(self, separator: impl Parser<U>) -> impl Parser<Vec<T>>
+    where
+        Self: Sized,
+    {
+        move |s: &str, pos: usize| {
+            let (first, mut pos) = self.parse_at(s, pos)?;
+            let mut values = vec![first];
+            while let Some((_, next)) = attempt(separator.parse_at(s, pos), pos)? {
+                let (value, next) = self.parse_at(s, next)?;
+                values.push(value);
+                pos = next;
+            }
+            Ok((values, pos))
+        }
+    }
+
+    fn spanned(self) -> impl Parser<Spanned<T>>
+    where
+        Self: Sized,
+    {
+        move |s: &str, pos: usize| {
+            let (value, next) = self.parse_at(s, pos)?;
+            Ok((
+                Spanned {
+                    value,
+                    span: Span::new(pos, next),
+                },
+                next,
+            ))
+        }
+    }
+
+    fn label<L: Display>(self, label: L) -> impl Parser<T>
+    where
+        Self: Sized,
+    {
+        move |s: &str, pos: usize| {
+            self.parse_at(s, pos).map_err(|e| {
+                if e.pos == pos {
+                    Failure::expected(pos, &label)
+                } else {
+                    e
+                }
+            })
+        }
+    }
+}
+
+impl<T, F: Fn(&str, usize) -> Parsed<T>> Parser<T> for F {
+    fn parse_at(&self, s: &str, pos: usize) -> Parsed<T> {
+        self(s, pos)
+    }
+}
+
+// A failure that consumed input is an error in what was being parsed rather
+// than a sign to stop repeating, so it is reported instead of backtracking.
+fn attempt<T>(parsed: Parsed<T>, pos: usize) -> Result<Option<(T, usize)>, Failure> {
+    match parsed {
+        Ok(parsed) => Ok(Some(parsed)),
+        Err(e) if e.pos > pos => Err(e),
+        Err(_) => Ok(None),
+    }
+}
+
+fn column(s: &str, pos: usize) -> usize {
+    s[..pos].chars().count() + 1
+}
+
+pub fn literal(expected: &str) -> impl Parser<()> + '_ {
+    move |s: &str, pos: usize| {
+        if s[pos..].starts_with(expected) {
+            Ok(((), pos + expected.len()))
+        } else {
+            Err(Failure::expected(pos, format!("'{}'", expected)))
+        }
+    }
+}
+
+pub fn satisfy<L: Display>(label: L, predicate: impl Fn(char) -> bool) -> impl Parser<char> {
+    move |s: &str, pos: usize| match s[pos..].chars().next() {
+        Some(c) if predicate(c) => Ok((c, pos + c.len_utf8())),
+        _ => Err(Failure::expected(pos, &label)),
+    }
+}
+
+fn take_while(s: &str, pos: usize, predicate: impl Fn(char) -> bool) -> usize {
+    s[pos..]
+        .char_indices()
+        .find(|&(_, c)| !predicate(c))
+        .map_or(s.len(), |(i, _)| pos + i)
+}
+
+pub fn whitespace() -> impl Parser<()> {
+    |s: &str, pos: usize| Ok(((), take_while(s, pos, char::is_whitespace)))
+}
+
+pub fn ws<T>(parser: impl Parser<T>) -> impl Parser<T> {
+    whitespace().right(parser).left(whitespace())
+}
+
+pub fn integer<T: Integer>() -> impl Parser<T> {
+    |s: &str, pos: usize| {
+        let start = pos + usize::from(s[pos..].starts_with(['-', '+']));
+        let end = take_while(s, start, |c| c.is_ascii_digit());
+        if end == start {
+            return Err(Failure::expected(pos, "integer"));
+        }
+        s[pos..end]
+            .parse()
+            .map(|value| (value, end))
+            .map_err(|e| Failure::message(pos, format!("invalid integer: {}", e)))
+    }
+}
+
+pub fn identifier() -> impl Parser<String> {
+    |s: &str, pos: usize| {
+        match s[pos..].chars().next() {
+            Some(c) if c.is_alphabetic() || c == '_' => {}
+            _ => return Err(Failure::expected(pos, "identifier")),
+        }
+        let end = take_while(s, pos, |c| c.is_alphanumeric() || c == '_');
+        Ok((s[pos..end].to_string(), end))
+    }
+}
+
+pub fn delimited<A, T, B>(
+    open: impl Parser<A>,
+    parser: impl Parser<T>,
+    close: impl Parser<B>,
+) -> impl Parser<T> {
+    open.right(parser).left(close)
+}
+
+pub fn end() -> impl Parser<()> {
+    |s: &str, pos: usize| {
+        if pos == s.len() {
+            Ok(((), pos))
+        } else {
+            Err(Failure::expected(pos, "end of input"))
+        }
+    }
+}
+
+pub fn complete<T>(parser: impl Parser<T>, s: &str) -> Result<T, Failure> {
+    let (value, pos) = parser.parse_at(s, 0)?;
+    end().parse_at(s, pos)?;
+    Ok(value)
+}
+
+pub fn parse_str<T>(parser: impl Parser<T>, s: &str) -> Result<T, ParseError> {
+    complete(parser, s).map_err(|e| e.at(1, s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Chunk {
+        open: char,
+        children: Vec<Spanned<Chunk>>,
+    }
+
+    fn chunk(s: &str, pos: usize) -> Parsed<Chunk> {
+        let pair = |open: &'static str, close: &'static str| {
+            delimited(literal(open), chunk.spanned().many(), literal(close)).map(move |children| {
+                Chunk {
+                    open: open.chars().next().unwrap(),
+                    children,
+                }
+            })
+        };
+        pair("(", ")")
+            .or(pair("[", "]"))
+            .or(pair("{", "}"))
+            .or(pair("<", ">"))
+            .parse_at(s, pos)
+    }
+
+    #[test]
+    fn check_literal() {
+        assert_eq!(literal("->").parse_at("a -> b", 2), Ok(((), 4)));
+        assert_eq!(
+            literal("->").parse_at("a => b", 2),
+            Err(Failure::expected(2, "'->'"))
+        );
+    }
+
+    #[test]
+    fn check_integer() {
+        assert_eq!(complete(integer::<i32>(), "-42"), Ok(-42));
+        assert_eq!(integer::<u8>().parse_at("12,", 0), Ok((12, 2)));
+        assert_eq!(
+            complete(integer::<u8>(), "300").unwrap_err().reason(),
+            "invalid integer: number too large to fit in target type"
+        );
+        assert_eq!(
+            complete(integer::<u8>(), "x").unwrap_err().reason(),
+            "expected integer"
+        );
+    }
+
+    #[test]
+    fn check_sequence() -> Result<(), ParseError> {
+        let point = integer::<u32>().left(literal(",")).then(integer::<u32>());
+        let line = ws(point.spanned())
+            .left(literal("->"))
+            .then(ws(integer::<u32>()
+                .left(literal(","))
+                .then(integer::<u32>())));
+
+        let (start, end) = parse_str(line, "0,9 -> 5,9")?;
+        assert_eq!(start.value, (0, 9));
+        assert_eq!(start.span, Span::new(0, 3));
+        assert_eq!(start.span.slice("0,9 -> 5,9"), "0,9");
+        assert_eq!(end, (5, 9));
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_separated() -> Result<(), ParseError> {
+        let list = integer::<i64>().separated_by(ws(literal(",")));
+        assert_eq!(parse_str(list, "3, 4,-3 ,1")?, vec![3, 4, -3, 1]);
+
+        let assignment = identifier()
+            .left(ws(literal("=")))
+            .then(integer::<u32>().separated_by(literal("..")));
+        assert_eq!(
+            parse_str(assignment, "x_1 = 20..30")?,
+            ("x_1".to_string(), vec![20, 30])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_alternation() {
+        let sign = literal("+").map(|_| 1).or(literal("-").map(|_| -1));
+        assert_eq!(complete(sign.many(), "+-+"), Ok(vec![1, -1, 1]));
+
+        let direction = identifier()
+            .label("direction")
+            .then(ws(integer::<u32>()).optional());
+        assert_eq!(
+            complete(direction, "up"),
+            Ok(("up".to_string(), None::<u32>))
+        );
+    }
+
+    #[test]
+    fn check_nested() -> Result<(), ParseError> {
+        let s = "[<>({}){}]";
+        let root = parse_str(chunk, s)?;
+
+        assert_eq!(root.open, '[');
+        assert_eq!(root.children.len(), 3);
+        assert_eq!(root.children[1].span.slice(s), "({})");
+        assert_eq!(root.children[1].value.children[0].span, Span::new(4, 6));
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_errors() {
+        assert_eq!(
+            parse_str(chunk, "{([(<{}[<>[]}>{[]{[(<()>")
+                .unwrap_err()
+                .to_string(),
+            "1:13: expected ']' in '{([(<{}[<>[]}>{[]{[(<()>'"
+        );
+        assert_eq!(
+            parse_str(chunk, "x").unwrap_err().to_string(),
+            "1:1: expected '(', '[', '{' or '<' in 'x'"
+        );
+        assert_eq!(
+            parse_str(chunk, "()x").unwrap_err().to_string(),
+            "1:3: expected end of input in '()x'"
+        );
+        assert_eq!(
+            Failure::expected(5, "integer").at(7, "abcdefg").to_string(),
+            "7:6: expected integer in 'abcdefg'"
+        );
+    }
+}
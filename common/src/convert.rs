@@ -1,17 +1,94 @@
+use std::{num::ParseIntError, str::FromStr};
+
+pub trait Integer: Copy + FromStr<Err = ParseIntError> {
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError>;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseIntError> {
+                    <$t>::from_str_radix(s, radix)
+                }
+            }
+        )*
+    };
+}
+
+integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+pub fn to<T: FromStr>(s: String) -> Option<T> {
+    s.trim().parse().ok()
+}
+
 pub fn to_u32(s: String) -> Option<u32> {
-    s.parse().ok()
+    to(s)
 }
 
 pub fn to_vec_u32(s: String) -> Option<Vec<u32>> {
     Some(s.chars().filter_map(|c| c.to_digit(10)).collect())
 }
 
-pub fn to_vec<T>(s: String, pattern: char, convert: fn(String) -> Option<T>) -> Vec<T> {
+pub fn to_vec<T>(s: String, pattern: char, convert: impl Fn(String) -> Option<T>) -> Vec<T> {
     s.split(pattern)
         .filter_map(|s| convert(s.to_string()))
         .collect()
 }
 
+pub fn try_to_vec<T: FromStr>(s: &str, pattern: char) -> Result<Vec<T>, T::Err> {
+    try_split(s, &[pattern])
+}
+
+pub fn from_radix<T: Integer>(s: &str, radix: u32) -> Result<T, ParseIntError> {
+    T::from_str_radix(s.trim(), radix)
+}
+
+pub fn from_binary<T: Integer>(s: &str) -> Result<T, ParseIntError> {
+    from_radix(s, 2)
+}
+
+pub fn digits<T: TryFrom<u32>>(s: &str, radix: u32) -> Option<Vec<T>> {
+    s.trim()
+        .chars()
+        .map(|c| c.to_digit(radix).and_then(|d| T::try_from(d).ok()))
+        .collect()
+}
+
+pub fn split_any<'a>(s: &'a str, delimiters: &'a [char]) -> impl Iterator<Item = &'a str> {
+    s.split(delimiters)
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+}
+
+pub fn try_split<T: FromStr>(s: &str, delimiters: &[char]) -> Result<Vec<T>, T::Err> {
+    split_any(s, delimiters).map(|s| s.parse()).collect()
+}
+
+pub fn ints<T: Integer>(s: &str) -> Result<Vec<T>, ParseIntError> {
+    let bytes = s.as_bytes();
+    let mut values = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && !(i > 0 && bytes[i - 1].is_ascii_alphanumeric());
+        if negative || bytes[i].is_ascii_digit() {
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            values.push(s[start..i].parse()?);
+        } else {
+            i += 1;
+        }
+    }
+
+    Ok(values)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -26,13 +103,70 @@ mod tests {
         assert_eq!(to_u32("ABC".to_string()), None);
     }
 
+    #[test]
+    fn check_to() {
+        assert_eq!(to::<i8>(" -12 ".to_string()), Some(-12));
+        assert_eq!(to::<u8>("256".to_string()), None);
+        assert_eq!(
+            to::<u128>("340282366920938463463374607431768211455".to_string()),
+            Some(u128::MAX)
+        );
+    }
+
     #[test]
     fn check_vec_u32() {
         assert_eq!(to_vec("2,4,6".to_string(), ',', to_u32), vec![2, 4, 6]);
     }
 
+    #[test]
+    fn check_try_to_vec() {
+        assert_eq!(try_to_vec::<u32>(" 8  2 23", ' '), Ok(vec![8, 2, 23]));
+        assert!(try_to_vec::<u32>("8,x", ',').is_err());
+    }
+
     #[test]
     fn check_vec() {
         assert_eq!(to_vec_u32("246".to_string()), Some(vec![2, 4, 6]));
     }
+
+    #[test]
+    fn check_radix() {
+        assert_eq!(from_binary::<u32>("10110"), Ok(22));
+        assert_eq!(from_radix::<i64>("-ff", 16), Ok(-255));
+        assert!(from_binary::<u8>("102").is_err());
+        assert!(from_binary::<u8>("111111111").is_err());
+    }
+
+    #[test]
+    fn check_digits() {
+        assert_eq!(digits::<u8>("2199943210", 10).unwrap()[..3], [2, 1, 9]);
+        assert_eq!(digits::<u64>("1f", 16), Some(vec![1, 15]));
+        assert_eq!(digits::<u8>("12a", 10), None);
+    }
+
+    #[test]
+    fn check_split_any() {
+        assert_eq!(
+            split_any(" 1, 2 ;3,,4 ", &[',', ';']).collect::<Vec<&str>>(),
+            vec!["1", "2", "3", "4"]
+        );
+        assert_eq!(
+            try_split::<i32>("-1 | 2,3", &['|', ',']),
+            Ok(vec![-1, 2, 3])
+        );
+        assert!(try_split::<u32>("1,b", &[',']).is_err());
+    }
+
+    #[test]
+    fn check_ints() {
+        assert_eq!(ints::<u32>("0,9 -> 5,9"), Ok(vec![0, 9, 5, 9]));
+        assert_eq!(
+            ints::<i64>("target area: x=-20..30, y=-10..-5"),
+            Ok(vec![-20, 30, -10, -5])
+        );
+        assert_eq!(ints::<u32>("1-3 a: abcde"), Ok(vec![1, 3]));
+        assert_eq!(ints::<u8>("no numbers"), Ok(vec![]));
+        assert!(ints::<u32>("x=-1").is_err());
+        assert!(ints::<u8>("300").is_err());
+    }
 }
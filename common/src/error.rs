@@ -0,0 +1,106 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug)]
+pub enum Reason {
+    Io(io::Error),
+    Invalid(String),
+}
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub path: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub reason: Reason,
+}
+
+impl ParseError {
+    pub fn invalid<T: Display>(line: usize, column: usize, text: &str, reason: T) -> Self {
+        Self {
+            path: None,
+            line,
+            column,
+            text: text.to_string(),
+            reason: Reason::Invalid(reason.to_string()),
+        }
+    }
+
+    pub fn io(line: usize, error: io::Error) -> Self {
+        Self {
+            path: None,
+            line,
+            column: 1,
+            text: String::new(),
+            reason: Reason::Io(error),
+        }
+    }
+
+    pub fn with_path<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.path = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    pub fn locate<P: AsRef<Path>>(error: anyhow::Error, path: P) -> anyhow::Error {
+        match error.downcast::<ParseError>() {
+            Ok(error) if error.path.is_none() => error.with_path(path).into(),
+            Ok(error) => error.into(),
+            Err(error) => error,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "{}:", path.display())?;
+        }
+        write!(f, "{}:{}: ", self.line, self.column)?;
+        match &self.reason {
+            Reason::Io(error) => write!(f, "{}", error),
+            Reason::Invalid(reason) => write!(f, "{} in '{}'", reason, self.text),
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.reason {
+            Reason::Io(error) => Some(error),
+            Reason::Invalid(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_display() {
+        let error = ParseError::invalid(3, 7, "forward x", "invalid digit found in string");
+
+        assert_eq!(
+            error.to_string(),
+            "3:7: invalid digit found in string in 'forward x'"
+        );
+        assert_eq!(
+            error.with_path("2021/day2/data/input.txt").to_string(),
+            "2021/day2/data/input.txt:3:7: invalid digit found in string in 'forward x'"
+        );
+    }
+
+    #[test]
+    fn check_locate() {
+        let error = ParseError::locate(ParseError::invalid(1, 1, "x", "bad").into(), "a.txt");
+        assert_eq!(error.to_string(), "a.txt:1:1: bad in 'x'");
+
+        let error = ParseError::locate(anyhow::anyhow!("other"), "a.txt");
+        assert_eq!(error.to_string(), "other");
+    }
+}
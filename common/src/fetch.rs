@@ -0,0 +1,244 @@
+use {
+    anyhow::{anyhow, Context, Result},
+    std::{
+        env, fs,
+        path::{Path, PathBuf},
+    },
+};
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const CACHE_VAR: &str = "AOC_CACHE";
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const CACHE: &str = ".cache";
+
+const USER_AGENT: &str = "adventofcode input fetcher";
+
+pub trait Http {
+    fn get(&self, url: &str, session: &str) -> Result<String>;
+}
+
+pub struct Ureq;
+
+impl Http for Ureq {
+    fn get(&self, url: &str, session: &str) -> Result<String> {
+        let mut response = ureq::get(url)
+            .header("Cookie", &format!("session={}", session))
+            .header("User-Agent", USER_AGENT)
+            .call()?;
+
+        Ok(response.body_mut().read_to_string()?)
+    }
+}
+
+pub struct Provider<H> {
+    http: H,
+    base_url: String,
+    session: Option<String>,
+    cache: PathBuf,
+}
+
+impl Provider<Ureq> {
+    pub fn from_env() -> Self {
+        Self::new(
+            Ureq,
+            &env::var(BASE_URL_VAR).unwrap_or_else(|_| BASE_URL.to_string()),
+            env::var(SESSION_VAR).ok().filter(|s| !s.trim().is_empty()),
+            env::var(CACHE_VAR).unwrap_or_else(|_| CACHE.to_string()),
+        )
+    }
+}
+
+impl<H: Http> Provider<H> {
+    pub fn new<P: AsRef<Path>>(http: H, base_url: &str, session: Option<String>, cache: P) -> Self {
+        Self {
+            http,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            cache: cache.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn url(&self, year: u32, day: u32) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    pub fn cache_path(&self, year: u32, day: u32) -> PathBuf {
+        self.cache
+            .join(year.to_string())
+            .join(format!("day{}.txt", day))
+    }
+
+    fn cached(&self, year: u32, day: u32) -> Option<String> {
+        fs::read_to_string(self.cache_path(year, day))
+            .ok()
+            .filter(|s| !s.is_empty())
+    }
+
+    fn download(&self, year: u32, day: u32) -> Result<String> {
+        let session = self
+            .session
+            .as_deref()
+            .ok_or_else(|| anyhow!("{} is not set", SESSION_VAR))?;
+        let url = self.url(year, day);
+        let input = self
+            .http
+            .get(&url, session)
+            .with_context(|| format!("Cannot fetch {}", url))?;
+
+        let path = self.cache_path(year, day);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, &input).with_context(|| format!("Cannot cache {}", path.display()))?;
+
+        Ok(input)
+    }
+
+    pub fn input(&self, year: u32, day: u32) -> Result<String> {
+        match self.cached(year, day) {
+            Some(input) => Ok(input),
+            None => self.download(year, day),
+        }
+    }
+
+    pub fn refresh(&self, year: u32, day: u32) -> Result<String> {
+        self.download(year, day)
+            .or_else(|e| self.cached(year, day).ok_or(e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        std::{cell::Cell, process, thread},
+        tiny_http::{Header, Response, Server},
+    };
+
+    struct Stub {
+        calls: Cell<usize>,
+        reply: Option<&'static str>,
+    }
+
+    impl Http for &Stub {
+        fn get(&self, url: &str, session: &str) -> Result<String> {
+            self.calls.set(self.calls.get() + 1);
+            assert_eq!(url, "http://stub/2021/day/6/input");
+            assert_eq!(session, "secret");
+            self.reply
+                .map(str::to_string)
+                .ok_or_else(|| anyhow!("offline"))
+        }
+    }
+
+    fn cache(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn check_url() {
+        let provider = Provider::new(Ureq, "http://localhost:8080/", None, "cache");
+
+        assert_eq!(
+            provider.url(2021, 6),
+            "http://localhost:8080/2021/day/6/input"
+        );
+        assert_eq!(
+            provider.cache_path(2021, 6),
+            Path::new("cache/2021/day6.txt")
+        );
+    }
+
+    #[test]
+    fn check_cache() -> Result<()> {
+        let dir = cache("cache");
+        let stub = Stub {
+            calls: Cell::new(0),
+            reply: Some("3,4,3,1,2\n"),
+        };
+        let provider = Provider::new(&stub, "http://stub", Some("secret".to_string()), &dir);
+
+        assert_eq!(provider.input(2021, 6)?, "3,4,3,1,2\n");
+        assert_eq!(provider.input(2021, 6)?, "3,4,3,1,2\n");
+        assert_eq!(stub.calls.get(), 1);
+        assert_eq!(
+            fs::read_to_string(dir.join("2021/day6.txt"))?,
+            "3,4,3,1,2\n"
+        );
+
+        provider.refresh(2021, 6)?;
+        assert_eq!(stub.calls.get(), 2);
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn check_offline() -> Result<()> {
+        let dir = cache("offline");
+        let stub = Stub {
+            calls: Cell::new(0),
+            reply: None,
+        };
+        let provider = Provider::new(&stub, "http://stub", Some("secret".to_string()), &dir);
+
+        assert!(provider.input(2021, 6).is_err());
+
+        fs::create_dir_all(dir.join("2021"))?;
+        fs::write(dir.join("2021/day6.txt"), "1,2\n")?;
+        assert_eq!(provider.refresh(2021, 6)?, "1,2\n");
+        assert_eq!(stub.calls.get(), 2);
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn check_missing_session() {
+        let dir = cache("session");
+        let stub = Stub {
+            calls: Cell::new(0),
+            reply: Some("1"),
+        };
+        let provider = Provider::new(&stub, "http://stub", None, &dir);
+
+        let error = provider.input(2021, 6).unwrap_err();
+        assert_eq!(error.to_string(), "AOC_SESSION is not set");
+        assert_eq!(stub.calls.get(), 0);
+    }
+
+    #[test]
+    fn check_ureq() -> Result<()> {
+        let server = Server::http("127.0.0.1:0").map_err(|e| anyhow!(e))?;
+        let base_url = format!("http://{}", server.server_addr());
+        let handle = thread::spawn(move || {
+            let request = server.recv().unwrap();
+            let cookie = request
+                .headers()
+                .iter()
+                .find(|h| h.field.equiv("Cookie"))
+                .map(|h| h.value.to_string());
+            let url = request.url().to_string();
+            let header = Header::from_bytes("Content-Type", "text/plain").unwrap();
+            request
+                .respond(Response::from_string("16,1,2\n").with_header(header))
+                .unwrap();
+            (url, cookie)
+        });
+
+        let dir = cache("ureq");
+        let provider = Provider::new(Ureq, &base_url, Some("secret".to_string()), &dir);
+        assert_eq!(provider.input(2021, 7)?, "16,1,2\n");
+
+        let (url, cookie) = handle.join().unwrap();
+        assert_eq!(url, "/2021/day/7/input");
+        assert_eq!(cookie.as_deref(), Some("session=secret"));
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,792 @@
+use {
+    crate::parse::{parse, PatternError},
+    std::{
+        iter::FusedIterator,
+        num::TryFromIntError,
+        ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+        str::FromStr,
+    },
+};
+
+// Screen coordinates: x grows to the right and y grows downwards, so Up is
+// (0, -1) and turning right rotates clockwise as seen on the page.
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(&self, other: Self) -> i64 {
+        (other - *self).manhattan()
+    }
+
+    pub fn chebyshev(&self, other: Self) -> i64 {
+        (other - *self).chebyshev()
+    }
+
+    pub fn orthogonal(&self) -> impl Iterator<Item = Point2> {
+        let point = *self;
+        Direction4::ALL.into_iter().map(move |d| point + d.vec())
+    }
+
+    pub fn adjacent(&self) -> impl Iterator<Item = Point2> {
+        let point = *self;
+        Direction8::ALL.into_iter().map(move |d| point + d.vec())
+    }
+
+    pub fn rotate_about(&self, centre: Self, quarter_turns: i32) -> Self {
+        centre + (*self - centre).rotate(quarter_turns)
+    }
+}
+
+impl Vec2 {
+    pub const ZERO: Self = Self::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(&self) -> i64 {
+        self.x.abs() + self.y.abs()
+    }
+
+    pub fn chebyshev(&self) -> i64 {
+        self.x.abs().max(self.y.abs())
+    }
+
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn turn_right(&self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    pub fn turn_left(&self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    pub fn rotate(&self, quarter_turns: i32) -> Self {
+        match quarter_turns.rem_euclid(4) {
+            0 => *self,
+            1 => self.turn_right(),
+            2 => -*self,
+            _ => self.turn_left(),
+        }
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Self = Self::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: Self) -> i64 {
+        (other - *self).manhattan()
+    }
+
+    pub fn chebyshev(&self, other: Self) -> i64 {
+        (other - *self).chebyshev()
+    }
+}
+
+impl Vec3 {
+    pub const ZERO: Self = Self::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(&self) -> i64 {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+
+    pub fn chebyshev(&self) -> i64 {
+        self.x.abs().max(self.y.abs()).max(self.z.abs())
+    }
+
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+}
+
+macro_rules! vector_ops {
+    ($point:ident, $vector:ident, $($field:ident),+) => {
+        impl Add<$vector> for $point {
+            type Output = $point;
+
+            fn add(self, v: $vector) -> $point {
+                $point { $($field: self.$field + v.$field),+ }
+            }
+        }
+
+        impl Sub<$vector> for $point {
+            type Output = $point;
+
+            fn sub(self, v: $vector) -> $point {
+                $point { $($field: self.$field - v.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $vector;
+
+            fn sub(self, other: $point) -> $vector {
+                $vector { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl AddAssign<$vector> for $point {
+            fn add_assign(&mut self, v: $vector) {
+                *self = *self + v;
+            }
+        }
+
+        impl SubAssign<$vector> for $point {
+            fn sub_assign(&mut self, v: $vector) {
+                *self = *self - v;
+            }
+        }
+
+        impl Add for $vector {
+            type Output = $vector;
+
+            fn add(self, v: $vector) -> $vector {
+                $vector { $($field: self.$field + v.$field),+ }
+            }
+        }
+
+        impl Sub for $vector {
+            type Output = $vector;
+
+            fn sub(self, v: $vector) -> $vector {
+                $vector { $($field: self.$field - v.$field),+ }
+            }
+        }
+
+        impl AddAssign for $vector {
+            fn add_assign(&mut self, v: $vector) {
+                *self = *self + v;
+            }
+        }
+
+        impl SubAssign for $vector {
+            fn sub_assign(&mut self, v: $vector) {
+                *self = *self - v;
+            }
+        }
+
+        impl Mul<i64> for $vector {
+            type Output = $vector;
+
+            fn mul(self, n: i64) -> $vector {
+                $vector { $($field: self.$field * n),+ }
+            }
+        }
+
+        impl Neg for $vector {
+            type Output = $vector;
+
+            fn neg(self) -> $vector {
+                $vector { $($field: -self.$field),+ }
+            }
+        }
+    };
+}
+
+vector_ops!(Point2, Vec2, x, y);
+vector_ops!(Point3, Vec3, x, y, z);
+
+impl From<(usize, usize)> for Point2 {
+    fn from((row, column): (usize, usize)) -> Self {
+        Self::new(column as i64, row as i64)
+    }
+}
+
+impl TryFrom<Point2> for (usize, usize) {
+    type Error = TryFromIntError;
+
+    fn try_from(point: Point2) -> Result<Self, Self::Error> {
+        Ok((usize::try_from(point.y)?, usize::try_from(point.x)?))
+    }
+}
+
+impl FromStr for Point2 {
+    type Err = PatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = parse("{x},{y}", s)?;
+        Ok(Self::new(x, y))
+    }
+}
+
+impl FromStr for Point3 {
+    type Err = PatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y, z) = parse("{x},{y},{z}", s)?;
+        Ok(Self::new(x, y, z))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    pub const fn vec(&self) -> Vec2 {
+        match self {
+            Self::Up => Vec2::new(0, -1),
+            Self::Right => Vec2::new(1, 0),
+            Self::Down => Vec2::new(0, 1),
+            Self::Left => Vec2::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        Self::ALL[(*self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(&self) -> Self {
+        Self::ALL[(*self as usize + 3) % 4]
+    }
+
+    pub fn opposite(&self) -> Self {
+        Self::ALL[(*self as usize + 2) % 4]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    pub const ALL: [Self; 8] = [
+        Self::N,
+        Self::NE,
+        Self::E,
+        Self::SE,
+        Self::S,
+        Self::SW,
+        Self::W,
+        Self::NW,
+    ];
+
+    pub const fn vec(&self) -> Vec2 {
+        match self {
+            Self::N => Vec2::new(0, -1),
+            Self::NE => Vec2::new(1, -1),
+            Self::E => Vec2::new(1, 0),
+            Self::SE => Vec2::new(1, 1),
+            Self::S => Vec2::new(0, 1),
+            Self::SW => Vec2::new(-1, 1),
+            Self::W => Vec2::new(-1, 0),
+            Self::NW => Vec2::new(-1, -1),
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        Self::ALL[(*self as usize + 1) % 8]
+    }
+
+    pub fn turn_left(&self) -> Self {
+        Self::ALL[(*self as usize + 7) % 8]
+    }
+
+    pub fn opposite(&self) -> Self {
+        Self::ALL[(*self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        !(*self as usize).is_multiple_of(2)
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds2 {
+    pub min: Point2,
+    pub max: Point2,
+}
+
+impl Bounds2 {
+    pub fn new(a: Point2, b: Point2) -> Self {
+        Self {
+            min: Point2::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point2::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    pub fn of(points: impl IntoIterator<Item = Point2>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, first), |bounds, p| bounds.include(p)))
+    }
+
+    pub fn include(&self, point: Point2) -> Self {
+        Self {
+            min: Point2::new(self.min.x.min(point.x), self.min.y.min(point.y)),
+            max: Point2::new(self.max.x.max(point.x), self.max.y.max(point.y)),
+        }
+    }
+
+    pub fn contains(&self, point: Point2) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    pub fn width(&self) -> i64 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i64 {
+        self.max.y - self.min.y + 1
+    }
+
+    pub fn area(&self) -> Option<i64> {
+        let width = self.max.x.checked_sub(self.min.x)?.checked_add(1)?;
+        let height = self.max.y.checked_sub(self.min.y)?.checked_add(1)?;
+        width.checked_mul(height)
+    }
+
+    pub fn index(&self, point: Point2) -> Option<usize> {
+        self.contains(point)
+            .then(|| ((point.y - self.min.y) * self.width() + point.x - self.min.x) as usize)
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point2> {
+        let (min, max) = (self.min, self.max);
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point2::new(x, y)))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Segment {
+    pub start: Point2,
+    pub end: Point2,
+}
+
+impl Segment {
+    pub fn new(start: Point2, end: Point2) -> Self {
+        Self { start, end }
+    }
+
+    pub fn delta(&self) -> Vec2 {
+        self.end - self.start
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        self.start.y == self.end.y
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.start.x == self.end.x
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        let delta = self.delta();
+        delta.x.abs() == delta.y.abs()
+    }
+
+    pub fn bounds(&self) -> Bounds2 {
+        Bounds2::new(self.start, self.end)
+    }
+
+    // The smallest lattice step along the segment, so that every integer
+    // point on it is start + step * k for k in 0..=count.
+    fn step(&self) -> (Vec2, i64) {
+        let delta = self.delta();
+        let count = gcd(delta.x.abs(), delta.y.abs());
+        if count == 0 {
+            (Vec2::ZERO, 0)
+        } else {
+            (Vec2::new(delta.x / count, delta.y / count), count)
+        }
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point2> {
+        let (step, count) = self.step();
+        let start = self.start;
+        (0..=count).map(move |k| start + step * k)
+    }
+
+    pub fn raster(&self) -> Raster {
+        Raster::new(self)
+    }
+
+    pub fn contains(&self, point: Point2) -> bool {
+        cross(point - self.start, self.delta()) == 0 && self.bounds().contains(point)
+    }
+
+    pub fn intersection(&self, other: &Segment) -> Option<Segment> {
+        let (d1, d2) = (self.delta(), other.delta());
+        let offset = other.start - self.start;
+        let denominator = cross(d1, d2);
+
+        if denominator != 0 {
+            let (t, u) = (cross(offset, d2), cross(offset, d1));
+            let (t, u, denominator) = if denominator < 0 {
+                (-t, -u, -denominator)
+            } else {
+                (t, u, denominator)
+            };
+            if !(0..=denominator).contains(&t) || !(0..=denominator).contains(&u) {
+                return None;
+            }
+            let (x, y) = (d1.x as i128 * t, d1.y as i128 * t);
+            if x % denominator != 0 || y % denominator != 0 {
+                return None;
+            }
+            let point = self.start + Vec2::new((x / denominator) as i64, (y / denominator) as i64);
+            return Some(Segment::new(point, point));
+        }
+
+        if d1 == Vec2::ZERO {
+            return other
+                .contains(self.start)
+                .then_some(Segment::new(self.start, self.start));
+        }
+        if cross(offset, d1) != 0 {
+            return None;
+        }
+
+        let (step, count) = self.step();
+        let index = |p: Point2| {
+            let v = p - self.start;
+            if step.x != 0 {
+                v.x / step.x
+            } else {
+                v.y / step.y
+            }
+        };
+        let (a, b) = (index(other.start), index(other.end));
+        let (low, high) = (a.min(b).max(0), a.max(b).min(count));
+
+        (low <= high).then(|| Segment::new(self.start + step * low, self.start + step * high))
+    }
+}
+
+fn cross(a: Vec2, b: Vec2) -> i128 {
+    a.x as i128 * b.y as i128 - a.y as i128 * b.x as i128
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Raster {
+    current: Option<Point2>,
+    end: Point2,
+    delta: Vec2,
+    step: Vec2,
+    error: i64,
+}
+
+impl Raster {
+    fn new(segment: &Segment) -> Self {
+        let delta = segment.delta();
+        let delta = Vec2::new(delta.x.abs(), -delta.y.abs());
+        Self {
+            current: Some(segment.start),
+            end: segment.end,
+            delta,
+            step: segment.delta().signum(),
+            error: delta.x + delta.y,
+        }
+    }
+}
+
+impl Iterator for Raster {
+    type Item = Point2;
+
+    fn next(&mut self) -> Option<Point2> {
+        let point = self.current?;
+        self.current = (point != self.end).then(|| {
+            let mut next = point;
+            let twice = 2 * self.error;
+            if twice >= self.delta.y {
+                self.error += self.delta.y;
+                next.x += self.step.x;
+            }
+            if twice <= self.delta.x {
+                self.error += self.delta.x;
+                next.y += self.step.y;
+            }
+            next
+        });
+        Some(point)
+    }
+}
+
+impl FusedIterator for Raster {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_arithmetic() {
+        let mut p = Point2::new(3, 4);
+        let v = Vec2::new(-1, 2);
+
+        assert_eq!(p + v, Point2::new(2, 6));
+        assert_eq!(p - v, Point2::new(4, 2));
+        assert_eq!(Point2::new(2, 6) - p, v);
+        assert_eq!(v * 3, Vec2::new(-3, 6));
+        assert_eq!(-v, Vec2::new(1, -2));
+        assert_eq!(v + v - v, v);
+
+        p += v;
+        p -= v * 2;
+        assert_eq!(p, Point2::new(4, 2));
+
+        assert_eq!(
+            Point3::new(1, 2, 3) + Vec3::new(1, 1, 1),
+            Point3::new(2, 3, 4)
+        );
+        assert_eq!(Point3::new(1, 2, 3) - Point3::ORIGIN, Vec3::new(1, 2, 3));
+    }
+
+    #[test]
+    fn check_distances() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(-3, 5);
+
+        assert_eq!(a.manhattan(b), 11);
+        assert_eq!(a.chebyshev(b), 7);
+        assert_eq!((b - a).signum(), Vec2::new(-1, 1));
+        assert_eq!(Point3::new(1, 1, 1).manhattan(Point3::new(-1, 2, 5)), 7);
+        assert_eq!(Point3::new(1, 1, 1).chebyshev(Point3::new(-1, 2, 5)), 4);
+    }
+
+    #[test]
+    fn check_directions() {
+        assert_eq!(Direction4::Up.turn_right(), Direction4::Right);
+        assert_eq!(Direction4::Up.turn_left(), Direction4::Left);
+        assert_eq!(Direction4::Left.opposite(), Direction4::Right);
+        assert_eq!(Direction8::NW.turn_right(), Direction8::N);
+        assert_eq!(Direction8::N.turn_left(), Direction8::NW);
+        assert_eq!(Direction8::NE.opposite(), Direction8::SW);
+        assert!(Direction8::SE.is_diagonal());
+        assert_eq!(Direction8::from(Direction4::Down), Direction8::S);
+
+        for d in Direction4::ALL {
+            assert_eq!(d.vec().turn_right(), d.turn_right().vec());
+            assert_eq!(Direction8::from(d).vec(), d.vec());
+        }
+        for d in Direction8::ALL {
+            assert_eq!(d.vec() + d.opposite().vec(), Vec2::ZERO);
+        }
+    }
+
+    #[test]
+    fn check_neighbours() {
+        let p = Point2::new(0, 0);
+
+        assert_eq!(p.orthogonal().count(), 4);
+        assert!(p.orthogonal().all(|n| n.manhattan(p) == 1));
+        assert_eq!(p.adjacent().count(), 8);
+        assert!(p.adjacent().all(|n| n.chebyshev(p) == 1));
+    }
+
+    #[test]
+    fn check_rotation() {
+        let v = Vec2::new(2, -1);
+
+        assert_eq!(v.turn_right(), Vec2::new(1, 2));
+        assert_eq!(v.turn_left(), Vec2::new(-1, -2));
+        assert_eq!(v.rotate(2), -v);
+        assert_eq!(v.rotate(-1), v.turn_left());
+        assert_eq!(v.rotate(4), v);
+        assert_eq!(
+            Point2::new(3, 1).rotate_about(Point2::new(1, 1), 1),
+            Point2::new(1, 3)
+        );
+    }
+
+    #[test]
+    fn check_positions() {
+        assert_eq!(Point2::from((2, 5)), Point2::new(5, 2));
+        assert_eq!(<(usize, usize)>::try_from(Point2::new(5, 2)), Ok((2, 5)));
+        assert!(<(usize, usize)>::try_from(Point2::new(-1, 2)).is_err());
+    }
+
+    #[test]
+    fn check_from_str() {
+        assert_eq!("-3, 4".parse(), Ok(Point2::new(-3, 4)));
+        assert_eq!("1,2,-3".parse(), Ok(Point3::new(1, 2, -3)));
+        assert!("1;2".parse::<Point2>().is_err());
+    }
+
+    fn segment(x1: i64, y1: i64, x2: i64, y2: i64) -> Segment {
+        Segment::new(Point2::new(x1, y1), Point2::new(x2, y2))
+    }
+
+    #[test]
+    fn check_segment() {
+        assert!(segment(0, 9, 5, 9).is_horizontal());
+        assert!(segment(7, 0, 7, 4).is_vertical());
+        assert!(segment(8, 0, 0, 8).is_diagonal());
+        assert!(!segment(0, 0, 2, 5).is_diagonal());
+        assert!(segment(0, 0, 4, 6).contains(Point2::new(2, 3)));
+        assert!(!segment(0, 0, 4, 6).contains(Point2::new(1, 1)));
+        assert!(!segment(0, 0, 4, 6).contains(Point2::new(6, 9)));
+        assert_eq!(
+            segment(0, 0, 4, 6).points().collect::<Vec<Point2>>(),
+            vec![Point2::new(0, 0), Point2::new(2, 3), Point2::new(4, 6)]
+        );
+        assert_eq!(segment(3, 3, 3, 3).points().count(), 1);
+    }
+
+    #[test]
+    fn check_raster() {
+        assert_eq!(
+            segment(0, 0, 2, 5).raster().collect::<Vec<Point2>>(),
+            vec![
+                Point2::new(0, 0),
+                Point2::new(0, 1),
+                Point2::new(1, 2),
+                Point2::new(1, 3),
+                Point2::new(2, 4),
+                Point2::new(2, 5)
+            ]
+        );
+        assert_eq!(
+            segment(3, 1, 0, 1).raster().collect::<Vec<Point2>>(),
+            vec![
+                Point2::new(3, 1),
+                Point2::new(2, 1),
+                Point2::new(1, 1),
+                Point2::new(0, 1)
+            ]
+        );
+        assert_eq!(segment(2, 2, 2, 2).raster().count(), 1);
+
+        for (x, y) in [(7, 3), (-7, 3), (3, -7), (-3, -7), (5, 5), (0, -4)] {
+            let s = segment(1, 1, 1 + x, 1 + y);
+            let points = s.raster().collect::<Vec<Point2>>();
+            assert_eq!(points.len() as i64, s.delta().chebyshev() + 1);
+            assert_eq!(points.last(), Some(&s.end));
+            assert!(points.windows(2).all(|w| w[0].chebyshev(w[1]) == 1));
+            assert!(s.points().all(|p| points.contains(&p)));
+        }
+    }
+
+    #[test]
+    fn check_intersection() {
+        let point = |x, y| Some(segment(x, y, x, y));
+
+        assert_eq!(
+            segment(0, 0, 4, 4).intersection(&segment(0, 4, 4, 0)),
+            point(2, 2)
+        );
+        assert_eq!(segment(0, 0, 3, 3).intersection(&segment(0, 3, 3, 0)), None);
+        assert_eq!(
+            segment(0, 0, 4, 0).intersection(&segment(4, 0, 4, 5)),
+            point(4, 0)
+        );
+        assert_eq!(segment(0, 0, 4, 0).intersection(&segment(5, 0, 5, 5)), None);
+        assert_eq!(
+            segment(0, 9, 5, 9).intersection(&segment(7, 9, 2, 9)),
+            Some(segment(2, 9, 5, 9))
+        );
+        assert_eq!(segment(0, 0, 6, 3).intersection(&segment(8, 4, 1, 0)), None);
+        assert_eq!(
+            segment(0, 0, 6, 3).intersection(&segment(8, 4, 2, 1)),
+            Some(segment(2, 1, 6, 3))
+        );
+        assert_eq!(segment(0, 0, 1, 1).intersection(&segment(0, 1, 1, 2)), None);
+        assert_eq!(
+            segment(2, 2, 2, 2).intersection(&segment(0, 0, 4, 4)),
+            point(2, 2)
+        );
+        assert_eq!(
+            segment(0, 0, 4, 4).intersection(&segment(3, 3, 3, 3)),
+            point(3, 3)
+        );
+        assert_eq!(segment(0, 0, 0, 0).intersection(&segment(1, 1, 1, 1)), None);
+    }
+
+    #[test]
+    fn check_bounds() {
+        let bounds =
+            Bounds2::of([Point2::new(3, -1), Point2::new(-2, 4), Point2::new(0, 0)]).unwrap();
+
+        assert_eq!(bounds.min, Point2::new(-2, -1));
+        assert_eq!(bounds.max, Point2::new(3, 4));
+        assert_eq!(bounds.width(), 6);
+        assert_eq!(bounds.height(), 6);
+        assert!(bounds.contains(Point2::new(3, 4)));
+        assert!(!bounds.contains(Point2::new(4, 4)));
+        assert_eq!(bounds.area(), Some(36));
+        assert_eq!(
+            Bounds2::new(Point2::ORIGIN, Point2::new(4_294_967_295, 4_294_967_295)).area(),
+            None
+        );
+        assert_eq!(bounds.points().count(), 36);
+        assert_eq!(bounds.points().next(), Some(bounds.min));
+        assert!(bounds
+            .points()
+            .enumerate()
+            .all(|(i, p)| bounds.index(p) == Some(i)));
+        assert_eq!(bounds.index(Point2::new(4, 0)), None);
+        assert_eq!(Bounds2::of([]), None);
+        assert_eq!(
+            Bounds2::new(Point2::new(2, 2), Point2::new(0, 1)),
+            Bounds2::new(Point2::new(0, 1), Point2::new(2, 2))
+        );
+    }
+}
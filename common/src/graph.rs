@@ -0,0 +1,343 @@
+use {
+    crate::{error::ParseError, input::try_inputs},
+    std::{
+        collections::{BTreeSet, HashMap},
+        hash::Hash,
+        io::BufRead,
+        result,
+    },
+};
+
+pub trait Visit<N> {
+    type State: Clone + Eq + Hash;
+
+    fn start(&self, node: &N) -> Self::State;
+
+    fn visit(&self, state: &Self::State, node: &N) -> Option<Self::State>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Visited<N: Ord> {
+    start: N,
+    seen: BTreeSet<N>,
+    repeated: bool,
+}
+
+impl<N: Ord + Clone> Visited<N> {
+    fn new(start: &N) -> Self {
+        Self {
+            start: start.clone(),
+            seen: BTreeSet::from([start.clone()]),
+            repeated: false,
+        }
+    }
+
+    fn with(&self, node: &N, repeated: bool) -> Self {
+        let mut visited = self.clone();
+        visited.seen.insert(node.clone());
+        visited.repeated |= repeated;
+        visited
+    }
+}
+
+pub struct Once<F>(pub F);
+
+impl<N: Ord + Clone + Hash, F: Fn(&N) -> bool> Visit<N> for Once<F> {
+    type State = Visited<N>;
+
+    fn start(&self, node: &N) -> Self::State {
+        Visited::new(node)
+    }
+
+    fn visit(&self, state: &Self::State, node: &N) -> Option<Self::State> {
+        if !(self.0)(node) {
+            Some(state.clone())
+        } else if state.seen.contains(node) {
+            None
+        } else {
+            Some(state.with(node, false))
+        }
+    }
+}
+
+pub struct Twice<F>(pub F);
+
+impl<N: Ord + Clone + Hash, F: Fn(&N) -> bool> Visit<N> for Twice<F> {
+    type State = Visited<N>;
+
+    fn start(&self, node: &N) -> Self::State {
+        Visited::new(node)
+    }
+
+    fn visit(&self, state: &Self::State, node: &N) -> Option<Self::State> {
+        if !(self.0)(node) {
+            Some(state.clone())
+        } else if !state.seen.contains(node) {
+            Some(state.with(node, false))
+        } else if !state.repeated && node != &state.start {
+            Some(state.with(node, true))
+        } else {
+            None
+        }
+    }
+}
+
+pub struct Predicate<F>(pub F);
+
+impl<N: Clone + Eq + Hash, F: Fn(&[N], &N) -> bool> Visit<N> for Predicate<F> {
+    type State = Vec<N>;
+
+    fn start(&self, node: &N) -> Self::State {
+        vec![node.clone()]
+    }
+
+    fn visit(&self, state: &Self::State, node: &N) -> Option<Self::State> {
+        (self.0)(state, node).then(|| {
+            let mut path = state.clone();
+            path.push(node.clone());
+            path
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    links: HashMap<N, Vec<N>>,
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Self {
+            links: HashMap::new(),
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_arc(&mut self, from: N, to: N) {
+        self.links.entry(to.clone()).or_default();
+        let links = self.links.entry(from).or_default();
+        if !links.contains(&to) {
+            links.push(to);
+        }
+    }
+
+    pub fn add_edge(&mut self, a: N, b: N) {
+        self.add_arc(a.clone(), b.clone());
+        self.add_arc(b, a);
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.links.contains_key(node)
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.links.keys()
+    }
+
+    pub fn neighbours(&self, node: &N) -> impl Iterator<Item = &N> {
+        self.links.get(node).into_iter().flatten()
+    }
+
+    pub fn arcs(&self) -> impl Iterator<Item = (&N, &N)> {
+        self.links
+            .iter()
+            .flat_map(|(from, links)| links.iter().map(move |to| (from, to)))
+    }
+
+    pub fn paths<V: Visit<N>>(&self, start: &N, end: &N, policy: &V) -> Vec<Vec<N>> {
+        let mut paths = Vec::new();
+        let mut path = vec![start.clone()];
+
+        self._paths(end, policy, &policy.start(start), &mut path, &mut paths);
+
+        paths
+    }
+
+    fn _paths<V: Visit<N>>(
+        &self,
+        end: &N,
+        policy: &V,
+        state: &V::State,
+        path: &mut Vec<N>,
+        paths: &mut Vec<Vec<N>>,
+    ) {
+        let current = path.last().expect("path always holds the start node");
+        if current == end {
+            paths.push(path.clone());
+            return;
+        }
+        for next in self.neighbours(current).cloned().collect::<Vec<N>>() {
+            if let Some(state) = policy.visit(state, &next) {
+                path.push(next);
+                self._paths(end, policy, &state, path, paths);
+                path.pop();
+            }
+        }
+    }
+
+    pub fn count_paths<V: Visit<N>>(&self, start: &N, end: &N, policy: &V) -> usize {
+        let mut memo = HashMap::new();
+
+        self._count_paths(start, end, policy, policy.start(start), &mut memo)
+    }
+
+    fn _count_paths<V: Visit<N>>(
+        &self,
+        current: &N,
+        end: &N,
+        policy: &V,
+        state: V::State,
+        memo: &mut HashMap<(N, V::State), usize>,
+    ) -> usize {
+        if current == end {
+            return 1;
+        }
+        let key = (current.clone(), state);
+        if let Some(count) = memo.get(&key) {
+            return *count;
+        }
+        let count = self
+            .neighbours(current)
+            .filter_map(|next| policy.visit(&key.1, next).map(|state| (next, state)))
+            .map(|(next, state)| self._count_paths(next, end, policy, state, memo))
+            .sum();
+        memo.insert(key, count);
+        count
+    }
+}
+
+impl Graph<String> {
+    pub fn parse_edges<R: BufRead>(reader: R) -> result::Result<Self, ParseError> {
+        let mut graph = Graph::new();
+
+        for (i, line) in try_inputs::<_, String>(reader)?.iter().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match line.split_once('-') {
+                Some((a, b)) if !a.trim().is_empty() && !b.trim().is_empty() => {
+                    graph.add_edge(a.trim().to_string(), b.trim().to_string())
+                }
+                Some((a, _)) => {
+                    return Err(ParseError::invalid(
+                        i + 1,
+                        a.len() + 1,
+                        line,
+                        "Missing node",
+                    ))
+                }
+                None => return Err(ParseError::invalid(i + 1, 1, line, "Missing '-'")),
+            }
+        }
+
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::io::Cursor};
+
+    fn small(node: &&str) -> bool {
+        node.chars().all(|c| c.is_lowercase())
+    }
+
+    fn test_graph() -> Graph<&'static str> {
+        let mut graph = Graph::new();
+        for (a, b) in [
+            ("start", "A"),
+            ("start", "b"),
+            ("A", "c"),
+            ("A", "b"),
+            ("b", "d"),
+            ("A", "end"),
+            ("b", "end"),
+        ] {
+            graph.add_edge(a, b);
+        }
+        graph
+    }
+
+    #[test]
+    fn check_add() {
+        let mut graph = Graph::new();
+        graph.add_edge("a", "b");
+        graph.add_edge("b", "a");
+        graph.add_arc("b", "c");
+
+        assert_eq!(graph.neighbours(&"a").collect::<Vec<_>>(), vec![&"b"]);
+        assert_eq!(graph.neighbours(&"b").collect::<Vec<_>>(), vec![&"a", &"c"]);
+        assert_eq!(graph.neighbours(&"c").count(), 0);
+        assert!(graph.contains(&"c"));
+        assert!(!graph.contains(&"d"));
+        assert_eq!(graph.arcs().count(), 3);
+    }
+
+    #[test]
+    fn check_paths_once() {
+        let mut paths = test_graph().paths(&"start", &"end", &Once(small));
+        paths.sort();
+
+        assert_eq!(paths.len(), 10);
+        assert_eq!(paths[0], vec!["start", "A", "b", "A", "c", "A", "end"]);
+        assert_eq!(paths[9], vec!["start", "b", "end"]);
+    }
+
+    #[test]
+    fn check_paths_twice() {
+        let graph = test_graph();
+
+        assert_eq!(graph.paths(&"start", &"end", &Twice(small)).len(), 36);
+    }
+
+    #[test]
+    fn check_paths_predicate() {
+        let graph = test_graph();
+        let short = Predicate(|path: &[&str], _: &&str| path.len() < 3);
+
+        let mut paths = graph.paths(&"start", &"end", &short);
+        paths.sort();
+
+        assert_eq!(
+            paths,
+            vec![vec!["start", "A", "end"], vec!["start", "b", "end"]]
+        );
+    }
+
+    #[test]
+    fn check_count_paths() {
+        let graph = test_graph();
+
+        assert_eq!(graph.count_paths(&"start", &"end", &Once(small)), 10);
+        assert_eq!(graph.count_paths(&"start", &"end", &Twice(small)), 36);
+    }
+
+    #[test]
+    fn check_parse_edges() {
+        let graph = Graph::parse_edges(Cursor::new("start-A\nA-end\n")).unwrap();
+
+        assert_eq!(graph.nodes().count(), 3);
+        assert_eq!(
+            graph.count_paths(
+                &"start".to_string(),
+                &"end".to_string(),
+                &Once(|n: &String| n == "start")
+            ),
+            1
+        );
+    }
+
+    #[test]
+    fn check_parse_edges_failure() {
+        let error = Graph::parse_edges(Cursor::new("start-A\nA end\n")).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = Graph::parse_edges(Cursor::new("start-A\nAA-\n")).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+}
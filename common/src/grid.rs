@@ -0,0 +1,343 @@
+use {
+    crate::{
+        error::ParseError,
+        geom::{Direction8, Point2},
+        input::try_inputs,
+    },
+    anyhow::{anyhow, Error, Result},
+    std::{
+        fmt::Display,
+        io::BufRead,
+        ops::{Index, IndexMut},
+        result,
+    },
+};
+
+pub type Position = (usize, usize);
+
+const ORTHOGONAL: [Direction8; 4] = [Direction8::N, Direction8::W, Direction8::E, Direction8::S];
+const DIAGONAL: [Direction8; 4] = [
+    Direction8::NW,
+    Direction8::NE,
+    Direction8::SW,
+    Direction8::SE,
+];
+const ADJACENT: [Direction8; 8] = [
+    Direction8::NW,
+    Direction8::N,
+    Direction8::NE,
+    Direction8::W,
+    Direction8::E,
+    Direction8::SW,
+    Direction8::S,
+    Direction8::SE,
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    columns: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, columns: usize, cells: Vec<T>) -> Result<Self> {
+        if cells.len() == rows * columns {
+            Ok(Self {
+                cells,
+                rows,
+                columns,
+            })
+        } else {
+            Err(anyhow!(
+                "Expected {} cells for {}x{} grid, found {}",
+                rows * columns,
+                rows,
+                columns,
+                cells.len()
+            ))
+        }
+    }
+
+    pub fn filled(rows: usize, columns: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; rows * columns],
+            rows,
+            columns,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (row, column): Position) -> bool {
+        row < self.rows && column < self.columns
+    }
+
+    fn offset(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.0 * self.columns + position.1)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.offset(position).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.offset(position).map(move |i| &mut self.cells[i])
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let columns = self.columns;
+        (0..self.rows).flat_map(move |r| (0..columns).map(move |c| (r, c)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    pub fn row(&self, row: usize) -> impl Iterator<Item = &T> {
+        let start = row.min(self.rows) * self.columns;
+        let end = (row + 1).min(self.rows) * self.columns;
+        self.cells[start..end].iter()
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        let start = if column < self.columns {
+            column
+        } else {
+            self.cells.len()
+        };
+        self.cells[start..].iter().step_by(self.columns.max(1))
+    }
+
+    pub fn orthogonal(&self, position: Position) -> impl Iterator<Item = Position> {
+        self.neighbours(position, &ORTHOGONAL)
+    }
+
+    pub fn diagonal(&self, position: Position) -> impl Iterator<Item = Position> {
+        self.neighbours(position, &DIAGONAL)
+    }
+
+    pub fn adjacent(&self, position: Position) -> impl Iterator<Item = Position> {
+        self.neighbours(position, &ADJACENT)
+    }
+
+    fn neighbours(
+        &self,
+        position: Position,
+        directions: &'static [Direction8],
+    ) -> impl Iterator<Item = Position> {
+        let (rows, columns) = (self.rows, self.columns);
+        let point = Point2::from(position);
+        directions.iter().filter_map(move |d| {
+            let (r, c) = Position::try_from(point + d.vec()).ok()?;
+            (r < rows && c < columns).then_some((r, c))
+        })
+    }
+
+    pub fn parse_with<R: BufRead, E: Display>(
+        convert: impl Fn(char) -> result::Result<T, E>,
+        reader: R,
+    ) -> result::Result<Self, ParseError> {
+        let mut columns = None;
+        let mut rows = 0;
+        let mut cells = Vec::new();
+
+        try_inputs::<_, String>(reader)?
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| !s.trim().is_empty())
+            .try_for_each(|(i, s)| {
+                let width = s.chars().count();
+                let expected = *columns.get_or_insert(width);
+                if expected != width {
+                    return Err(ParseError::invalid(
+                        i + 1,
+                        1,
+                        s,
+                        format!("expected {} columns, found {}", expected, width),
+                    ));
+                }
+                for (j, ch) in s.chars().enumerate() {
+                    cells.push(
+                        convert(ch)
+                            .map_err(|e| ParseError::invalid(i + 1, j + 1, &ch.to_string(), e))?,
+                    );
+                }
+                rows += 1;
+                Ok(())
+            })?;
+
+        Ok(Self {
+            cells,
+            rows,
+            columns: columns.unwrap_or(0),
+        })
+    }
+}
+
+impl Grid<u32> {
+    pub fn digits<R: BufRead>(reader: R) -> result::Result<Self, ParseError> {
+        Self::parse_with(
+            |ch| ch.to_digit(10).ok_or_else(|| anyhow!("Invalid digit")),
+            reader,
+        )
+    }
+}
+
+impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
+    type Error = Error;
+
+    fn try_from(values: Vec<Vec<T>>) -> Result<Self> {
+        let rows = values.len();
+        let columns = values.first().map_or(0, |v| v.len());
+        if let Some(row) = values.iter().position(|v| v.len() != columns) {
+            return Err(anyhow!(
+                "Row {} has {} columns, expected {}",
+                row,
+                values[row].len(),
+                columns
+            ));
+        }
+
+        Self::new(rows, columns, values.into_iter().flatten().collect())
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", position))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::io::Cursor};
+
+    fn test_grid() -> Grid<u32> {
+        Grid::try_from(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]).unwrap()
+    }
+
+    #[test]
+    fn check_new() {
+        assert!(Grid::new(2, 2, vec![1, 2, 3, 4]).is_ok());
+        assert!(Grid::new(2, 2, vec![1, 2, 3]).is_err());
+        assert!(Grid::try_from(vec![vec![1, 2], vec![3]]).is_err());
+    }
+
+    #[test]
+    fn check_get() {
+        let mut grid = test_grid();
+
+        assert_eq!(grid.rows(), 3);
+        assert_eq!(grid.columns(), 3);
+        assert_eq!(grid.get((1, 2)), Some(&6));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+
+        *grid.get_mut((2, 0)).unwrap() = 0;
+        grid[(0, 0)] += 10;
+        assert_eq!(grid[(2, 0)], 0);
+        assert_eq!(grid[(0, 0)], 11);
+    }
+
+    #[test]
+    fn check_rows_and_columns() {
+        let grid = test_grid();
+
+        assert_eq!(grid.row(1).copied().collect::<Vec<u32>>(), vec![4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<u32>>(), vec![3, 6, 9]);
+        assert_eq!(grid.row(3).count(), 0);
+        assert_eq!(grid.column(3).count(), 0);
+    }
+
+    #[test]
+    fn check_iter() {
+        let grid = test_grid();
+
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &5)));
+        assert_eq!(grid.positions().last(), Some((2, 2)));
+    }
+
+    #[test]
+    fn check_orthogonal() {
+        let grid = test_grid();
+
+        assert_eq!(
+            grid.orthogonal((0, 0)).collect::<Vec<Position>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.orthogonal((1, 1)).collect::<Vec<Position>>(),
+            vec![(0, 1), (1, 0), (1, 2), (2, 1)]
+        );
+    }
+
+    #[test]
+    fn check_diagonal() {
+        let grid = test_grid();
+
+        assert_eq!(
+            grid.diagonal((0, 2)).collect::<Vec<Position>>(),
+            vec![(1, 1)]
+        );
+        assert_eq!(grid.diagonal((1, 1)).count(), 4);
+    }
+
+    #[test]
+    fn check_adjacent() {
+        let grid = test_grid();
+
+        assert_eq!(grid.adjacent((2, 2)).count(), 3);
+        assert_eq!(grid.adjacent((1, 1)).count(), 8);
+        assert_eq!(grid.adjacent((1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn check_digits() {
+        let grid = Grid::digits(Cursor::new("123\n456\n789\n")).unwrap();
+
+        assert_eq!(grid, test_grid());
+    }
+
+    #[test]
+    fn check_digits_failure() {
+        let error = Grid::digits(Cursor::new("123\n4x6\n789")).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.text, "x");
+
+        let error = Grid::digits(Cursor::new("123\n45\n789")).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
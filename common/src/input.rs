@@ -1,21 +1,107 @@
 use {
-    crate::convert::to_vec,
+    crate::{
+        combinator::{complete, Parser},
+        convert::to_vec,
+        error::ParseError,
+    },
+    flate2::bufread::MultiGzDecoder,
     std::{
+        convert::Infallible,
+        fmt::{self, Display, Formatter},
         fs::File,
-        io::{BufRead, BufReader, Result},
-        path::Path,
+        io::{self, BufRead, BufReader, Cursor, Result},
+        iter, mem,
+        path::{Path, PathBuf},
+        result,
+        str::FromStr,
     },
+    zstd::Decoder,
 };
 
+const GZIP: &[u8] = &[0x1f, 0x8b];
+const ZSTD: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+
 pub fn from_path(path: &str) -> Result<impl BufRead> {
     from_file(Path::new(path))
 }
 
-fn from_file(path: &Path) -> Result<impl BufRead> {
-    Ok(BufReader::new(File::open(path)?))
+fn from_file(path: &Path) -> Result<Box<dyn BufRead>> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let extension = path.extension().and_then(|e| e.to_str());
+    let magic = reader.fill_buf()?;
+    let (gzip, zstd) = (magic.starts_with(GZIP), magic.starts_with(ZSTD));
+
+    Ok(match extension {
+        Some("gz") => Box::new(BufReader::new(MultiGzDecoder::new(reader))),
+        Some("zst") => Box::new(BufReader::new(Decoder::with_buffer(reader)?)),
+        _ if gzip => Box::new(BufReader::new(MultiGzDecoder::new(reader))),
+        _ if zstd => Box::new(BufReader::new(Decoder::with_buffer(reader)?)),
+        _ => Box::new(reader),
+    })
 }
 
-pub fn inputs<R: BufRead, T>(convert: fn(String) -> Option<T>, reader: R) -> Vec<T> {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl Source {
+    pub fn open(&self) -> Result<Box<dyn BufRead>> {
+        Ok(match self {
+            Source::Path(path) => from_file(path)?,
+            Source::Stdin => Box::new(io::stdin().lock()),
+            Source::Text(text) => Box::new(Cursor::new(text.clone())),
+        })
+    }
+
+    pub fn from_args<I: IntoIterator<Item = String>>(
+        args: I,
+        default: &str,
+    ) -> result::Result<Self, String> {
+        let args = args.into_iter().collect::<Vec<String>>();
+        match args.as_slice() {
+            [] => Ok(Source::Path(PathBuf::from(default))),
+            [flag, text] if flag == "--text" => Ok(Source::Text(text.to_string())),
+            [source] if !source.starts_with("--") => {
+                Ok(source.parse().unwrap_or_else(|e| match e {}))
+            }
+            _ => Err(format!(
+                "Expected [PATH | - | --text INPUT], found {}",
+                args.join(" ")
+            )),
+        }
+    }
+}
+
+impl FromStr for Source {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> result::Result<Self, Infallible> {
+        Ok(match s {
+            "-" => Source::Stdin,
+            path => Source::Path(PathBuf::from(path)),
+        })
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Path(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::Text(_) => write!(f, "<text>"),
+        }
+    }
+}
+
+pub fn inputs<R: BufRead, T: FromStr>(reader: R) -> Vec<T> {
+    inputs_with(|s| s.parse().ok(), reader)
+}
+
+#[allow(clippy::lines_filter_map_ok)]
+pub fn inputs_with<R: BufRead, T>(convert: impl Fn(String) -> Option<T>, reader: R) -> Vec<T> {
     reader
         .lines()
         .filter_map(|result| result.ok())
@@ -23,54 +109,284 @@ pub fn inputs<R: BufRead, T>(convert: fn(String) -> Option<T>, reader: R) -> Vec
         .collect()
 }
 
-pub fn list<R: BufRead, T>(convert: fn(String) -> Option<T>, reader: R) -> Vec<T> {
+pub fn list<R: BufRead, T: FromStr>(reader: R) -> Vec<T> {
+    list_by(',', reader)
+}
+
+#[allow(clippy::lines_filter_map_ok)]
+pub fn list_by<R: BufRead, T: FromStr>(separator: char, reader: R) -> Vec<T> {
     reader
         .lines()
         .filter_map(|result| result.ok())
-        .map(|s| to_vec(s, ',', convert))
-        .flatten()
+        .flat_map(|s| to_vec(s, separator, |s| s.trim().parse().ok()))
         .collect()
 }
 
-pub fn batch<R: BufRead, T: Clone>(
-    convert: fn(String) -> Option<T>,
-    batch: fn(&Option<T>) -> bool,
+#[allow(clippy::lines_filter_map_ok)]
+pub fn batch<R: BufRead, T>(
+    convert: impl Fn(String) -> Option<T>,
+    batch: impl Fn(&Option<T>) -> bool,
     reader: R,
 ) -> Vec<Vec<T>> {
-    let mut store: Vec<T> = Vec::new();
-    reader
-        .lines()
-        .filter_map(|result| result.ok())
-        .map(convert)
-        .filter_map(|value| match batch(&value) {
-            false if value.is_some() => {
-                store.push(value.unwrap());
-                None
+    let mut batches = Vec::new();
+    let mut store = Vec::new();
+    for value in reader.lines().filter_map(|result| result.ok()).map(convert) {
+        if batch(&value) {
+            batches.push(mem::take(&mut store));
+        } else if let Some(value) = value {
+            store.push(value);
+        }
+    }
+    if !store.is_empty() {
+        batches.push(store);
+    }
+    batches
+}
+
+fn numbered<R: BufRead>(
+    reader: R,
+) -> impl Iterator<Item = result::Result<(usize, String), ParseError>> {
+    let mut lines = reader.lines().enumerate();
+    let mut failed = false;
+    iter::from_fn(move || {
+        if failed {
+            return None;
+        }
+        let (i, line) = lines.next()?;
+        failed = line.is_err();
+        Some(
+            line.map(|s| (i + 1, s))
+                .map_err(|e| ParseError::io(i + 1, e)),
+        )
+    })
+}
+
+pub fn lines_of<T>(reader: impl BufRead) -> impl Iterator<Item = result::Result<T, ParseError>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    lines_with(|s| s.parse::<T>(), reader)
+}
+
+pub fn lines_with<T, E: Display>(
+    convert: impl Fn(String) -> result::Result<T, E>,
+    reader: impl BufRead,
+) -> impl Iterator<Item = result::Result<T, ParseError>> {
+    numbered(reader).map(move |line| {
+        let (number, s) = line?;
+        convert(s.clone()).map_err(|e| ParseError::invalid(number, 1, &s, e))
+    })
+}
+
+pub fn lines_parsed<T>(
+    parser: impl Parser<T>,
+    reader: impl BufRead,
+) -> impl Iterator<Item = result::Result<T, ParseError>> {
+    numbered(reader).map(move |line| {
+        let (number, s) = line?;
+        complete(parser.by_ref(), &s).map_err(|e| e.at(number, &s))
+    })
+}
+
+pub fn records_by_blank_line(
+    reader: impl BufRead,
+) -> impl Iterator<Item = result::Result<Vec<String>, ParseError>> {
+    records_with(Ok::<String, Infallible>, reader)
+}
+
+pub fn records_with<T, E: Display>(
+    convert: impl Fn(String) -> result::Result<T, E>,
+    reader: impl BufRead,
+) -> impl Iterator<Item = result::Result<Vec<T>, ParseError>> {
+    records_by(|s| s.trim().is_empty(), convert, reader)
+}
+
+pub fn records_by<T, E: Display>(
+    separator: impl Fn(&str) -> bool,
+    convert: impl Fn(String) -> result::Result<T, E>,
+    reader: impl BufRead,
+) -> impl Iterator<Item = result::Result<Vec<T>, ParseError>> {
+    let mut failed = false;
+    sections_by(separator, reader).map_while(move |section| {
+        if failed {
+            return None;
+        }
+        let record = section.and_then(|section| section.parse_with(&convert));
+        failed = record.is_err();
+        Some(record)
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    pub line: usize,
+    pub lines: Vec<String>,
+}
+
+impl Section {
+    fn locate(&self, mut error: ParseError) -> ParseError {
+        error.line += self.line - 1;
+        error
+    }
+
+    fn reader(&self) -> Cursor<String> {
+        Cursor::new(self.lines.join("\n"))
+    }
+
+    pub fn parse<T>(&self) -> result::Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.parse_with(|s| s.parse::<T>())
+    }
+
+    pub fn parse_with<T, E: Display>(
+        &self,
+        convert: impl Fn(String) -> result::Result<T, E>,
+    ) -> result::Result<Vec<T>, ParseError> {
+        try_inputs_with(convert, self.reader()).map_err(|e| self.locate(e))
+    }
+
+    pub fn list<T>(&self) -> result::Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.list_by(',')
+    }
+
+    pub fn list_by<T>(&self, separator: char) -> result::Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        try_list_by(separator, self.reader()).map_err(|e| self.locate(e))
+    }
+}
+
+pub fn sections(reader: impl BufRead) -> impl Iterator<Item = result::Result<Section, ParseError>> {
+    sections_by(|s| s.trim().is_empty(), reader)
+}
+
+pub fn sections_by(
+    separator: impl Fn(&str) -> bool,
+    reader: impl BufRead,
+) -> impl Iterator<Item = result::Result<Section, ParseError>> {
+    let mut lines = numbered(reader);
+    iter::from_fn(move || {
+        let mut section: Option<Section> = None;
+        for line in lines.by_ref() {
+            let (number, s) = match line {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e)),
+            };
+            if separator(&s) {
+                if section.is_some() {
+                    break;
+                }
+            } else {
+                section
+                    .get_or_insert_with(|| Section {
+                        line: number,
+                        lines: Vec::new(),
+                    })
+                    .lines
+                    .push(s);
             }
-            false => None,
-            true => {
-                let result = store.clone();
-                store.clear();
-                Some(result)
+        }
+        section.map(Ok)
+    })
+}
+
+pub fn try_inputs<R: BufRead, T>(reader: R) -> result::Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    try_inputs_with(|s| s.parse::<T>(), reader)
+}
+
+pub fn try_inputs_with<R: BufRead, T, E: Display>(
+    convert: impl Fn(String) -> result::Result<T, E>,
+    reader: R,
+) -> result::Result<Vec<T>, ParseError> {
+    lines_with(convert, reader).collect()
+}
+
+pub fn try_inputs_parsed<R: BufRead, T>(
+    parser: impl Parser<T>,
+    reader: R,
+) -> result::Result<Vec<T>, ParseError> {
+    lines_parsed(parser, reader).collect()
+}
+
+pub fn try_list<R: BufRead, T>(reader: R) -> result::Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    try_list_by(',', reader)
+}
+
+pub fn try_list_by<R: BufRead, T>(separator: char, reader: R) -> result::Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut values = Vec::new();
+    for line in numbered(reader) {
+        let (number, s) = line?;
+        let mut column = 1;
+        for item in s.split(separator) {
+            let offset = item.len() - item.trim_start().len();
+            let trimmed = item.trim();
+            if !trimmed.is_empty() {
+                values.push(
+                    trimmed
+                        .parse()
+                        .map_err(|e| ParseError::invalid(number, column + offset, trimmed, e))?,
+                );
             }
-        })
-        .collect()
+            column += item.len() + separator.len_utf8();
+        }
+    }
+    Ok(values)
+}
+
+pub fn try_batch_with<R: BufRead, T, E: Display>(
+    convert: impl Fn(String) -> result::Result<T, E>,
+    reader: R,
+) -> result::Result<Vec<Vec<T>>, ParseError> {
+    records_with(convert, reader).collect()
 }
 
 #[cfg(test)]
 mod tests {
-    use {super::*, crate::convert::to_u32, std::io::Cursor};
+    use {
+        super::*,
+        crate::{
+            combinator::{integer, literal},
+            convert::to_u32,
+            error::Reason,
+            testing::fixture_path,
+        },
+        std::io::{self, Read},
+    };
 
     #[test]
     fn check_reader() {
         let buffer = Cursor::new(b"100\n200\n300");
-        assert_eq!(inputs(to_u32, buffer), vec![100, 200, 300]);
+        assert_eq!(inputs::<_, u32>(buffer), vec![100, 200, 300]);
     }
 
     #[test]
     fn check_file() {
         assert_eq!(
-            inputs(to_u32, from_file(Path::new("test/test.txt")).unwrap()),
+            inputs::<_, u32>(
+                from_file(&fixture_path(env!("CARGO_MANIFEST_DIR"), "test.txt")).unwrap()
+            ),
             vec![101, 201, 301]
         );
     }
@@ -78,15 +394,52 @@ mod tests {
     #[test]
     fn check_path() {
         assert_eq!(
-            inputs(to_u32, from_path("test/test.txt").unwrap()),
+            inputs::<_, u32>(crate::fixture!("test.txt").unwrap()),
             vec![101, 201, 301]
         );
     }
 
+    #[test]
+    fn check_compressed() {
+        for name in ["test.txt.gz", "test.txt.zst", "gzipped.txt"] {
+            let path = fixture_path(env!("CARGO_MANIFEST_DIR"), name);
+            assert_eq!(
+                inputs::<_, u32>(from_file(&path).unwrap()),
+                vec![101, 201, 301],
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn check_compressed_failure() {
+        let path = fixture_path(env!("CARGO_MANIFEST_DIR"), "test.txt");
+        let error = try_inputs::<_, u32>(from_file(&path.with_extension("txt.broken.gz")).unwrap());
+
+        assert!(matches!(error.unwrap_err().reason, Reason::Io(_)));
+    }
+
+    #[test]
+    fn check_inputs_with() {
+        let offset = 10;
+        let buffer = Cursor::new(b"100\nABC\n300");
+        assert_eq!(
+            inputs_with(|s| to_u32(s).map(|n| n + offset), buffer),
+            vec![110, 310]
+        );
+    }
+
     #[test]
     fn check_list() {
         let buffer = Cursor::new("100,200,300\n400,500");
-        assert_eq!(list(to_u32, buffer), vec![100, 200, 300, 400, 500]);
+        assert_eq!(list::<_, u32>(buffer), vec![100, 200, 300, 400, 500]);
+    }
+
+    #[test]
+    fn check_list_by() {
+        let buffer = Cursor::new(" 8  2 23\n 4 24");
+        assert_eq!(list_by::<_, u32>(' ', buffer), vec![8, 2, 23, 4, 24]);
     }
 
     #[test]
@@ -97,4 +450,246 @@ mod tests {
             vec![vec![1, 2, 3], vec![4, 5, 6]]
         );
     }
+
+    #[test]
+    fn check_batch_trailing() {
+        let buffer = Cursor::new("1\n2\n\n3");
+        assert_eq!(
+            batch(to_u32, |n| n.is_none(), buffer),
+            vec![vec![1, 2], vec![3]]
+        );
+    }
+
+    #[test]
+    fn check_try_inputs() {
+        let buffer = Cursor::new(b"100\n200\n300");
+        assert_eq!(try_inputs::<_, u32>(buffer).unwrap(), vec![100, 200, 300]);
+    }
+
+    #[test]
+    fn check_try_inputs_failure() {
+        let buffer = Cursor::new(b"100\n2x0\n300");
+        let error = try_inputs::<_, u32>(buffer).unwrap_err();
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 1);
+        assert_eq!(error.text, "2x0");
+        assert!(matches!(error.reason, Reason::Invalid(_)));
+    }
+
+    #[test]
+    fn check_try_inputs_with() {
+        let buffer = Cursor::new(b"1\n-2");
+        let error = try_inputs_with(|s| s.parse::<u32>(), buffer).unwrap_err();
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.text, "-2");
+    }
+
+    #[test]
+    fn check_try_list() {
+        let buffer = Cursor::new("100,200,300\n400, 500");
+        assert_eq!(
+            try_list::<_, u32>(buffer).unwrap(),
+            vec![100, 200, 300, 400, 500]
+        );
+    }
+
+    #[test]
+    fn check_try_list_failure() {
+        let buffer = Cursor::new("1,2,3\n4, x,6");
+        let error = try_list::<_, u32>(buffer).unwrap_err();
+
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 4);
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    fn check_try_list_by() {
+        let buffer = Cursor::new(" 8  2 23\n 4 24");
+        assert_eq!(
+            try_list_by::<_, u32>(' ', buffer).unwrap(),
+            vec![8, 2, 23, 4, 24]
+        );
+
+        let buffer = Cursor::new(" 8  x 23");
+        let error = try_list_by::<_, u32>(' ', buffer).unwrap_err();
+        assert_eq!(error.column, 5);
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    fn check_try_batch_with() {
+        let buffer = Cursor::new("1\n2\n3\n\n4\n5\n6");
+        assert_eq!(
+            try_batch_with(|s| s.parse::<u32>(), buffer).unwrap(),
+            vec![vec![1, 2, 3], vec![4, 5, 6]]
+        );
+    }
+
+    #[test]
+    fn check_try_batch_with_failure() {
+        let buffer = Cursor::new("1\n2\n\nthree");
+        let error = try_batch_with(|s| s.parse::<u32>(), buffer).unwrap_err();
+
+        assert_eq!(error.line, 4);
+        assert_eq!(error.text, "three");
+    }
+
+    #[test]
+    fn check_lines_of() {
+        let values = lines_of::<u32>(Cursor::new("1\nx\n3"))
+            .map(|value| value.map_err(|e| e.line))
+            .collect::<Vec<_>>();
+
+        assert_eq!(values, vec![Ok(1), Err(2), Ok(3)]);
+    }
+
+    #[test]
+    fn check_lines_of_lazy() {
+        let endless = Cursor::new("7\n").chain(BufReader::new(io::repeat(b'\n')));
+        let values = lines_of::<String>(endless).take(3).collect::<Vec<_>>();
+
+        assert_eq!(values.len(), 3);
+        assert_eq!(values[0].as_deref().unwrap(), "7");
+    }
+
+    #[test]
+    fn check_lines_with() -> result::Result<(), ParseError> {
+        let total = lines_with(|s| s.parse::<u32>(), Cursor::new("1\n2\n3"))
+            .try_fold(0, |total, value| value.map(|v| total + v))?;
+
+        assert_eq!(total, 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_lines_parsed() {
+        let point = integer::<u32>().left(literal(",")).then(integer::<u32>());
+        let values = lines_parsed(point.by_ref(), Cursor::new("1,2\n3;4\n5,6"))
+            .map(|value| value.map_err(|e| (e.line, e.column)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(values, vec![Ok((1, 2)), Err((2, 2)), Ok((5, 6))]);
+        assert_eq!(
+            try_inputs_parsed(point, Cursor::new("1,2\n3,x"))
+                .unwrap_err()
+                .to_string(),
+            "2:3: expected integer in '3,x'"
+        );
+    }
+
+    #[test]
+    fn check_records_by_blank_line() {
+        let records = records_by_blank_line(Cursor::new("\na\nb\n\n\nc"))
+            .collect::<result::Result<Vec<Vec<String>>, ParseError>>()
+            .unwrap();
+
+        assert_eq!(records, vec![vec!["a", "b"], vec!["c"]]);
+    }
+
+    #[test]
+    fn check_records_lazy() {
+        let endless = Cursor::new("a\nb\n\nc\n").chain(BufReader::new(io::repeat(b'\n')));
+        let records = records_by_blank_line(endless)
+            .take(2)
+            .collect::<result::Result<Vec<Vec<String>>, ParseError>>()
+            .unwrap();
+
+        assert_eq!(records, vec![vec!["a", "b"], vec!["c"]]);
+    }
+
+    #[test]
+    fn check_records_with_failure() {
+        let mut records = records_with(|s| s.parse::<u32>(), Cursor::new("1\n\nx\n\n3"));
+
+        assert_eq!(records.next().unwrap().unwrap(), vec![1]);
+        assert_eq!(records.next().unwrap().unwrap_err().line, 3);
+        assert!(records.next().is_none());
+    }
+
+    #[test]
+    fn check_records_by() {
+        let records = records_by(
+            |s| s == "--",
+            |s| s.parse::<u32>(),
+            Cursor::new("1\n2\n--\n3\n--\n--\n4"),
+        )
+        .collect::<result::Result<Vec<Vec<u32>>, ParseError>>()
+        .unwrap();
+
+        assert_eq!(records, vec![vec![1, 2], vec![3], vec![4]]);
+    }
+
+    #[test]
+    fn check_sections() -> result::Result<(), ParseError> {
+        let mut sections = sections(Cursor::new("7,4,9\n\n22 13\n 8  2\n\n3 15\n9 18"));
+
+        let numbers = sections.next().unwrap()?.list::<u32>()?;
+        let boards = sections
+            .map(|section| section?.parse_with(|s| crate::convert::try_to_vec::<u32>(&s, ' ')))
+            .collect::<result::Result<Vec<Vec<Vec<u32>>>, ParseError>>()?;
+
+        assert_eq!(numbers, vec![7, 4, 9]);
+        assert_eq!(boards.len(), 2);
+        assert_eq!(boards[1], vec![vec![3, 15], vec![9, 18]]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_sections_failure() {
+        let mut sections = sections(Cursor::new("1,2\n\n3\n4 x"));
+        sections.next();
+        let error = sections
+            .next()
+            .unwrap()
+            .unwrap()
+            .list_by::<u32>(' ')
+            .unwrap_err();
+
+        assert_eq!((error.line, error.column), (4, 3));
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    fn check_source() {
+        let args = |args: &[&str]| {
+            Source::from_args(
+                args.iter().map(|s| s.to_string()),
+                "2021/day1/data/input.txt",
+            )
+        };
+
+        assert_eq!(
+            args(&[]),
+            Ok(Source::Path(PathBuf::from("2021/day1/data/input.txt")))
+        );
+        assert_eq!(args(&["-"]), Ok(Source::Stdin));
+        assert_eq!(
+            args(&["big.txt"]),
+            Ok(Source::Path(PathBuf::from("big.txt")))
+        );
+        assert_eq!(
+            args(&["--text", "1\n2"]),
+            Ok(Source::Text("1\n2".to_string()))
+        );
+        assert!(args(&["--text"]).is_err());
+        assert!(args(&["a.txt", "b.txt"]).is_err());
+    }
+
+    #[test]
+    fn check_source_open() -> Result<()> {
+        let text = Source::Text("1\n2\n3".to_string());
+        assert_eq!(inputs::<_, u32>(text.open()?), vec![1, 2, 3]);
+        assert_eq!(text.to_string(), "<text>");
+
+        let path = Source::Path(fixture_path(env!("CARGO_MANIFEST_DIR"), "test.txt"));
+        assert_eq!(inputs::<_, u32>(path.open()?), vec![101, 201, 301]);
+        assert_eq!(Source::Stdin.to_string(), "<stdin>");
+
+        Ok(())
+    }
 }
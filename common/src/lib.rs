@@ -1,2 +1,12 @@
+pub mod answers;
+pub mod combinator;
 pub mod convert;
+pub mod error;
+pub mod fetch;
+pub mod geom;
+pub mod graph;
+pub mod grid;
 pub mod input;
+pub mod parse;
+pub mod solution;
+pub mod testing;
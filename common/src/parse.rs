@@ -0,0 +1,377 @@
+use std::{
+    any::type_name,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    mem,
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternError {
+    pub column: usize,
+    pub reason: String,
+}
+
+impl PatternError {
+    fn new<T: Display>(column: usize, reason: T) -> Self {
+        Self {
+            column,
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl Display for PatternError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at column {}", self.reason, self.column)
+    }
+}
+
+impl Error for PatternError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Piece {
+    Literal(String),
+    Capture(Option<String>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    pieces: Vec<Piece>,
+}
+
+impl Pattern {
+    pub fn new(pattern: &str) -> Result<Self, PatternError> {
+        let mut pieces = Vec::new();
+        let mut literal = String::new();
+        let mut chars = pattern.chars().enumerate().peekable();
+
+        while let Some((i, ch)) = chars.next() {
+            match ch {
+                '{' if chars.next_if(|&(_, c)| c == '{').is_some() => literal.push('{'),
+                '}' if chars.next_if(|&(_, c)| c == '}').is_some() => literal.push('}'),
+                '{' => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            Some((_, '}')) => break,
+                            Some((_, c)) => name.push(c),
+                            None => return Err(PatternError::new(i + 1, "unclosed '{'")),
+                        }
+                    }
+                    if literal.is_empty() {
+                        if let Some(Piece::Capture(_)) = pieces.last() {
+                            return Err(PatternError::new(i + 1, "adjacent captures"));
+                        }
+                    } else {
+                        pieces.push(Piece::Literal(mem::take(&mut literal)));
+                    }
+                    let name = name.trim();
+                    pieces.push(Piece::Capture((!name.is_empty()).then(|| name.to_string())));
+                }
+                '}' => return Err(PatternError::new(i + 1, "unmatched '}'")),
+                c => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            pieces.push(Piece::Literal(literal));
+        }
+
+        Ok(Self { pieces })
+    }
+
+    pub fn captures<'a>(&'a self, s: &'a str) -> Result<Captures<'a>, PatternError> {
+        let mut values = Vec::new();
+        let mut pos = 0;
+        let mut pieces = self.pieces.iter().peekable();
+
+        while let Some(piece) = pieces.next() {
+            match piece {
+                Piece::Literal(literal) => {
+                    if !s[pos..].starts_with(literal.as_str()) {
+                        return Err(PatternError::new(
+                            column(s, pos),
+                            format!("expected '{}'", literal),
+                        ));
+                    }
+                    pos += literal.len();
+                }
+                Piece::Capture(name) => {
+                    let end = match pieces.peek() {
+                        Some(Piece::Literal(literal)) => s[pos..]
+                            .find(literal.as_str())
+                            .map(|i| pos + i)
+                            .ok_or_else(|| {
+                                PatternError::new(column(s, pos), format!("expected '{}'", literal))
+                            })?,
+                        _ => s.len(),
+                    };
+                    let raw = &s[pos..end];
+                    let text = raw.trim();
+                    let start = pos + (raw.len() - raw.trim_start().len());
+                    if text.is_empty() {
+                        return Err(PatternError::new(
+                            column(s, pos),
+                            format!("missing {}", label(name.as_deref())),
+                        ));
+                    }
+                    if name.as_deref() == Some(WORD) && text.contains(char::is_whitespace) {
+                        return Err(PatternError::new(
+                            column(s, start),
+                            format!("expected a single word for {{{}}} in '{}'", WORD, text),
+                        ));
+                    }
+                    values.push(Capture {
+                        name: name.as_deref(),
+                        column: column(s, start),
+                        text,
+                    });
+                    pos = end;
+                }
+            }
+        }
+        if pos < s.len() {
+            return Err(PatternError::new(
+                column(s, pos),
+                format!("unexpected '{}'", &s[pos..]),
+            ));
+        }
+
+        Ok(Captures { values })
+    }
+
+    pub fn parse<T: FromCaptures>(&self, s: &str) -> Result<T, PatternError> {
+        T::from_captures(&self.captures(s)?)
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = PatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Pattern::new(s)
+    }
+}
+
+pub fn parse<T: FromCaptures>(pattern: &str, s: &str) -> Result<T, PatternError> {
+    Pattern::new(pattern)?.parse(s)
+}
+
+// Names that also constrain the capture: `{word}` takes no whitespace and an
+// integer type name must be read as that type.
+const WORD: &str = "word";
+const INTEGERS: [&str; 12] = [
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
+fn column(s: &str, pos: usize) -> usize {
+    s[..pos].chars().count() + 1
+}
+
+fn label(name: Option<&str>) -> String {
+    match name {
+        Some(name) => format!("{{{}}}", name),
+        None => "value".to_string(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Capture<'a> {
+    name: Option<&'a str>,
+    column: usize,
+    text: &'a str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Captures<'a> {
+    values: Vec<Capture<'a>>,
+}
+
+impl<'a> Captures<'a> {
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn str(&self, index: usize) -> Option<&'a str> {
+        self.values.get(index).map(|capture| capture.text)
+    }
+
+    pub fn get<T: FromStr>(&self, index: usize) -> Result<T, PatternError>
+    where
+        T::Err: Display,
+    {
+        let capture = self
+            .values
+            .get(index)
+            .ok_or_else(|| PatternError::new(1, format!("missing capture {}", index + 1)))?;
+        if let Some(name) = capture.name.filter(|name| INTEGERS.contains(name)) {
+            if type_name::<T>() != name {
+                return Err(PatternError::new(
+                    capture.column,
+                    format!("{{{}}} cannot be read as {}", name, type_name::<T>()),
+                ));
+            }
+        }
+        capture.text.parse().map_err(|e| {
+            PatternError::new(
+                capture.column,
+                format!("invalid {} '{}': {}", label(capture.name), capture.text, e),
+            )
+        })
+    }
+
+    pub fn expect(&self, count: usize) -> Result<(), PatternError> {
+        if self.len() == count {
+            Ok(())
+        } else {
+            Err(PatternError::new(
+                1,
+                format!("expected {} captures, found {}", count, self.len()),
+            ))
+        }
+    }
+}
+
+pub trait FromCaptures: Sized {
+    fn from_captures(captures: &Captures) -> Result<Self, PatternError>;
+}
+
+macro_rules! tuple {
+    ($count:literal: $($t:ident $i:tt),+) => {
+        impl<$($t: FromStr),+> FromCaptures for ($($t,)+)
+        where
+            $($t::Err: Display),+
+        {
+            fn from_captures(captures: &Captures) -> Result<Self, PatternError> {
+                captures.expect($count)?;
+                Ok(($(captures.get::<$t>($i)?,)+))
+            }
+        }
+    };
+}
+
+tuple!(1: A 0);
+tuple!(2: A 0, B 1);
+tuple!(3: A 0, B 1, C 2);
+tuple!(4: A 0, B 1, C 2, D 3);
+tuple!(5: A 0, B 1, C 2, D 3, E 4);
+tuple!(6: A 0, B 1, C 2, D 3, E 4, F 5);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_parse() {
+        assert_eq!(
+            parse::<(u32, u32, u32, u32)>("{},{} -> {},{}", "0,9 -> 5,9"),
+            Ok((0, 9, 5, 9))
+        );
+        assert_eq!(
+            parse::<(String, i32)>("{word} {i32}", "down -5"),
+            Ok(("down".to_string(), -5))
+        );
+        assert_eq!(
+            parse::<(String, String)>("{} | {}", "ab cd | efg"),
+            Ok(("ab cd".to_string(), "efg".to_string()))
+        );
+        assert_eq!(
+            parse::<(char, u8)>("x={{{}}} y={}", "x={a} y=7"),
+            Ok(('a', 7))
+        );
+    }
+
+    #[test]
+    fn check_captures() -> Result<(), PatternError> {
+        let pattern: Pattern = "target area: x={}..{}, y={}..{}".parse()?;
+        let captures = pattern.captures("target area: x=20..30, y=-10..-5")?;
+
+        assert_eq!(captures.len(), 4);
+        assert_eq!(captures.str(2), Some("-10"));
+        assert_eq!(captures.get::<i64>(3)?, -5);
+        assert!(captures.get::<i64>(4).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_typed_names() {
+        assert_eq!(
+            parse::<(String, u8)>("{word}: {u8}", "sue: 7"),
+            Ok(("sue".to_string(), 7))
+        );
+        assert_eq!(
+            parse::<(String,)>("{word}", "ab cd").unwrap_err(),
+            PatternError::new(1, "expected a single word for {word} in 'ab cd'")
+        );
+        let error = parse::<(String, String)>("{word} {u32}", "a b").unwrap_err();
+        assert_eq!(error.column, 3);
+        assert!(error.reason.starts_with("{u32} cannot be read as "));
+        assert_eq!(
+            parse::<(String, i64)>("{word} {u32}", "a 5").unwrap_err(),
+            PatternError::new(3, "{u32} cannot be read as i64")
+        );
+        assert_eq!(
+            parse::<(String,)>("{name}", "ab cd"),
+            Ok(("ab cd".to_string(),))
+        );
+    }
+
+    #[test]
+    fn check_pattern_errors() {
+        assert_eq!(
+            Pattern::new("{},{").unwrap_err(),
+            PatternError::new(4, "unclosed '{'")
+        );
+        assert_eq!(
+            Pattern::new("{}}").unwrap_err(),
+            PatternError::new(3, "unmatched '}'")
+        );
+        assert_eq!(
+            Pattern::new("{}{}").unwrap_err(),
+            PatternError::new(3, "adjacent captures")
+        );
+    }
+
+    #[test]
+    fn check_match_errors() {
+        let pattern = Pattern::new("{},{} -> {},{}").unwrap();
+
+        assert_eq!(
+            pattern
+                .parse::<(u32, u32, u32, u32)>("0,9 > 5,9")
+                .unwrap_err(),
+            PatternError::new(3, "expected ' -> '")
+        );
+        assert_eq!(
+            pattern
+                .parse::<(u32, u32, u32, u32)>("0,9 -> x,9")
+                .unwrap_err()
+                .to_string(),
+            "invalid value 'x': invalid digit found in string at column 8"
+        );
+        assert_eq!(
+            pattern.parse::<(u32, u32, u32)>("0,9 -> 5,9").unwrap_err(),
+            PatternError::new(1, "expected 3 captures, found 4")
+        );
+        assert_eq!(
+            parse::<(String, u32)>("{word} {u32}", "forward x").unwrap_err(),
+            PatternError::new(
+                9,
+                "invalid {u32} 'x': invalid digit found in string".to_string()
+            )
+        );
+        assert_eq!(
+            parse::<(String,)>("[{}]", "[a]b").unwrap_err(),
+            PatternError::new(4, "unexpected 'b'")
+        );
+        assert_eq!(
+            parse::<(String, u32)>("{word} {u32}", "forward ").unwrap_err(),
+            PatternError::new(9, "missing {u32}")
+        );
+    }
+}
@@ -0,0 +1,381 @@
+use {
+    crate::{error::ParseError, input::Source},
+    anyhow::{anyhow, Result},
+    std::{
+        env,
+        fmt::{self, Display, Formatter},
+        io::BufRead,
+        time::{Duration, Instant},
+    },
+};
+
+pub trait Solution {
+    const YEAR: u32;
+    const DAY: u32;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn all() -> [Part; 2] {
+        [Part::One, Part::Two]
+    }
+
+    pub fn number(&self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u32> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(n: u32) -> Result<Self> {
+        match n {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            n => Err(anyhow::anyhow!("Invalid part {}", n)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub year: u32,
+    pub day: u32,
+    pub part: Part,
+    pub value: String,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} Day {} Part {} => {}",
+            self.year,
+            self.day,
+            self.part.number(),
+            self.value
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub year: u32,
+    pub day: u32,
+    pub parse: Duration,
+    pub answers: Vec<(Part, Result<Answer>, Duration)>,
+}
+
+impl Report {
+    pub fn total(&self) -> Duration {
+        self.parse
+            + self
+                .answers
+                .iter()
+                .map(|(_, _, elapsed)| *elapsed)
+                .sum::<Duration>()
+    }
+
+    pub fn into_answers(self) -> Result<Vec<Answer>> {
+        self.answers
+            .into_iter()
+            .map(|(_, answer, _)| answer)
+            .collect()
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> Result<T>) -> (Result<T>, Duration) {
+    let start = Instant::now();
+    let value = f();
+
+    (value, start.elapsed())
+}
+
+pub fn solve_timed<S: Solution, R: BufRead>(reader: R, parts: &[Part]) -> Result<Report> {
+    let (input, parse) = timed(|| S::parse(reader));
+    let input = input?;
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let (value, elapsed) = match part {
+                Part::One => timed(|| Ok(S::part1(&input)?.to_string())),
+                Part::Two => timed(|| Ok(S::part2(&input)?.to_string())),
+            };
+            let answer = value.map(|value| Answer {
+                year: S::YEAR,
+                day: S::DAY,
+                part,
+                value,
+            });
+            (part, answer, elapsed)
+        })
+        .collect();
+
+    Ok(Report {
+        year: S::YEAR,
+        day: S::DAY,
+        parse,
+        answers,
+    })
+}
+
+pub fn solve<S: Solution, R: BufRead>(reader: R, parts: &[Part]) -> Result<Vec<Answer>> {
+    solve_timed::<S, R>(reader, parts)?.into_answers()
+}
+
+pub fn crate_path(year: u32, day: u32) -> String {
+    format!("{}/day{}", year, day)
+}
+
+pub fn data_path(year: u32, day: u32, name: &str) -> String {
+    format!("{}/data/{}", crate_path(year, day), name)
+}
+
+pub fn input_path(year: u32, day: u32) -> String {
+    data_path(year, day, "input.txt")
+}
+
+pub fn run<S: Solution>() -> Result<()> {
+    let source = Source::from_args(env::args().skip(1), &input_path(S::YEAR, S::DAY))
+        .map_err(|e| anyhow!(e))?;
+    let report = Puzzle::of::<S>().time_source(&source, &Part::all())?;
+    let mut failed = 0;
+    for (part, answer, _) in report.answers {
+        match answer {
+            Ok(answer) => println!("{}", answer),
+            Err(e) => {
+                eprintln!(
+                    "{} Day {} Part {} failed => {:#}",
+                    S::YEAR,
+                    S::DAY,
+                    part.number(),
+                    e
+                );
+                failed += 1;
+            }
+        }
+    }
+
+    match failed {
+        0 => Ok(()),
+        n => Err(anyhow!("{} part(s) failed", n)),
+    }
+}
+
+type SolveFn = fn(Box<dyn BufRead>, &[Part]) -> Result<Report>;
+
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub year: u32,
+    pub day: u32,
+    solve: SolveFn,
+}
+
+impl Puzzle {
+    pub fn of<S: Solution>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            solve: solve_timed::<S, Box<dyn BufRead>>,
+        }
+    }
+
+    pub fn data_path(&self, name: &str) -> String {
+        data_path(self.year, self.day, name)
+    }
+
+    pub fn input_path(&self) -> String {
+        input_path(self.year, self.day)
+    }
+
+    pub fn answers_path(&self) -> String {
+        data_path(self.year, self.day, "answers.toml")
+    }
+
+    pub fn solve(&self, reader: Box<dyn BufRead>, parts: &[Part]) -> Result<Vec<Answer>> {
+        self.time(reader, parts)?.into_answers()
+    }
+
+    pub fn solve_path(&self, path: &str, parts: &[Part]) -> Result<Vec<Answer>> {
+        self.time_path(path, parts)?.into_answers()
+    }
+
+    pub fn solve_source(&self, source: &Source, parts: &[Part]) -> Result<Vec<Answer>> {
+        self.time_source(source, parts)?.into_answers()
+    }
+
+    pub fn time(&self, reader: Box<dyn BufRead>, parts: &[Part]) -> Result<Report> {
+        (self.solve)(reader, parts)
+    }
+
+    pub fn time_path(&self, path: &str, parts: &[Part]) -> Result<Report> {
+        self.time_source(&Source::Path(path.into()), parts)
+    }
+
+    pub fn time_source(&self, source: &Source, parts: &[Part]) -> Result<Report> {
+        let locate = |e| ParseError::locate(e, source.to_string());
+        let mut report = self.time(source.open()?, parts).map_err(locate)?;
+        report.answers = report
+            .answers
+            .into_iter()
+            .map(|(part, answer, elapsed)| (part, answer.map_err(locate), elapsed))
+            .collect();
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::input::try_inputs, std::io::Cursor};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u32 = 2000;
+        const DAY: u32 = 0;
+
+        type Input = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = usize;
+
+        fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+            Ok(try_inputs(reader)?)
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Part1> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Self::Part2> {
+            Ok(input.len())
+        }
+    }
+
+    struct Half;
+
+    impl Solution for Half {
+        const YEAR: u32 = 2000;
+        const DAY: u32 = 1;
+
+        type Input = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = u32;
+
+        fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+            Ok(try_inputs(reader)?)
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Part1> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(_input: &Self::Input) -> Result<Self::Part2> {
+            Err(anyhow!("Part 2 failed"))
+        }
+    }
+
+    #[test]
+    fn check_part() {
+        assert_eq!(Part::try_from(1).unwrap(), Part::One);
+        assert_eq!(Part::try_from(2).unwrap(), Part::Two);
+        assert!(Part::try_from(3).is_err());
+    }
+
+    #[test]
+    fn check_solve() -> Result<()> {
+        let answers = solve::<Sum, _>(Cursor::new("1\n2\n3"), &Part::all())?;
+
+        assert_eq!(
+            answers
+                .iter()
+                .map(|a| a.to_string())
+                .collect::<Vec<String>>(),
+            vec!["2000 Day 0 Part 1 => 6", "2000 Day 0 Part 2 => 3"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_puzzle() -> Result<()> {
+        let puzzle = Puzzle::of::<Sum>();
+        let answers = puzzle.solve(Box::new(Cursor::new("1\n2\n3")), &[Part::Two])?;
+
+        assert_eq!(puzzle.input_path(), "2000/day0/data/input.txt");
+        assert_eq!(puzzle.answers_path(), "2000/day0/data/answers.toml");
+        assert_eq!(answers.len(), 1);
+        assert_eq!(answers[0].value, "3");
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_time() -> Result<()> {
+        let report = Puzzle::of::<Sum>().time(Box::new(Cursor::new("1\n2\n3")), &Part::all())?;
+
+        assert_eq!(report.day, 0);
+        assert_eq!(report.answers.len(), 2);
+        assert_eq!(report.answers[1].1.as_ref().unwrap().value, "3");
+        assert!(report.total() >= report.parse);
+        assert_eq!(report.into_answers()?.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_puzzle_failure() {
+        let puzzle = Puzzle::of::<Sum>();
+        let error = puzzle
+            .solve(Box::new(Cursor::new("1\nx\n3")), &[Part::One])
+            .unwrap_err();
+
+        assert_eq!(
+            ParseError::locate(error, "sum.txt").to_string(),
+            "sum.txt:2:1: invalid digit found in string in 'x'"
+        );
+
+        let error = puzzle
+            .solve_source(&Source::Text("1\nx".to_string()), &[Part::One])
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "<text>:2:1: invalid digit found in string in 'x'"
+        );
+    }
+
+    #[test]
+    fn check_part_failure() -> Result<()> {
+        let report = Puzzle::of::<Half>().time(Box::new(Cursor::new("1\n2")), &Part::all())?;
+
+        assert_eq!(report.answers.len(), 2);
+        assert_eq!(report.answers[0].1.as_ref().unwrap().value, "3");
+        assert_eq!(report.answers[1].0, Part::Two);
+        assert_eq!(
+            report.answers[1].1.as_ref().unwrap_err().to_string(),
+            "Part 2 failed"
+        );
+        assert!(report.into_answers().is_err());
+
+        Ok(())
+    }
+}
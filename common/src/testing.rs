@@ -0,0 +1,146 @@
+use {
+    crate::{
+        input::from_path,
+        solution::{solve, Part, Solution},
+    },
+    anyhow::{anyhow, Result},
+    std::{
+        io::{self, BufRead},
+        path::{Path, PathBuf},
+    },
+};
+
+pub fn fixture_path(manifest_dir: &str, name: &str) -> PathBuf {
+    Path::new(manifest_dir).join("test").join(name)
+}
+
+pub fn open(manifest_dir: &str, name: &str) -> io::Result<impl BufRead> {
+    let path = fixture_path(manifest_dir, name);
+    from_path(&path.to_string_lossy())
+}
+
+pub fn part(name: &str) -> Result<Part> {
+    name.strip_prefix("part")
+        .and_then(|n| n.parse::<u32>().ok())
+        .ok_or_else(|| anyhow!("Expected part1 or part2, found {}", name))
+        .and_then(Part::try_from)
+}
+
+pub fn check<S: Solution>(
+    manifest_dir: &str,
+    name: &str,
+    expected: &[(&str, String)],
+) -> Result<()> {
+    let parts = expected
+        .iter()
+        .map(|(part_name, _)| part(part_name))
+        .collect::<Result<Vec<Part>>>()?;
+    let answers = solve::<S, _>(open(manifest_dir, name)?, &parts)?;
+
+    for (answer, (part_name, value)) in answers.iter().zip(expected) {
+        if &answer.value != value {
+            return Err(anyhow!(
+                "{} of {}: expected {}, found {}",
+                part_name,
+                name,
+                value,
+                answer.value
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[macro_export]
+macro_rules! fixture {
+    ($name:expr) => {
+        $crate::testing::open(env!("CARGO_MANIFEST_DIR"), $name)
+    };
+}
+
+#[macro_export]
+macro_rules! examples {
+    ($solution:ty; $($test:ident: $name:literal => { $($part:ident: $value:expr),+ $(,)? }),+ $(,)?) => {
+        $(
+            #[test]
+            fn $test() {
+                let expected = [$((stringify!($part), ($value).to_string())),+];
+                if let Err(e) = $crate::testing::check::<$solution>(
+                    env!("CARGO_MANIFEST_DIR"),
+                    $name,
+                    &expected,
+                ) {
+                    panic!("{:#}", e);
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::input::try_inputs};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u32 = 2000;
+        const DAY: u32 = 0;
+
+        type Input = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = usize;
+
+        fn parse<R: BufRead>(reader: R) -> Result<Self::Input> {
+            Ok(try_inputs(reader)?)
+        }
+
+        fn part1(input: &Self::Input) -> Result<Self::Part1> {
+            Ok(input.iter().sum())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Self::Part2> {
+            Ok(input.len())
+        }
+    }
+
+    examples! {
+        Sum;
+        check_example: "test.txt" => { part1: 603, part2: 3 },
+        check_example_part2: "test.txt" => { part2: 3 },
+    }
+
+    #[test]
+    fn check_fixture_path() {
+        assert_eq!(
+            fixture_path("/repo/2021/day9", "test.txt"),
+            Path::new("/repo/2021/day9/test/test.txt")
+        );
+        assert!(fixture!("test.txt").is_ok());
+        assert!(fixture!("missing.txt").is_err());
+    }
+
+    #[test]
+    fn check_part() {
+        assert_eq!(part("part1").unwrap(), Part::One);
+        assert_eq!(part("part2").unwrap(), Part::Two);
+        assert!(part("part3").is_err());
+        assert!(part("one").is_err());
+    }
+
+    #[test]
+    fn check_mismatch() {
+        let error = check::<Sum>(
+            env!("CARGO_MANIFEST_DIR"),
+            "test.txt",
+            &[("part1", "600".to_string())],
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "part1 of test.txt: expected 600, found 603"
+        );
+    }
+}